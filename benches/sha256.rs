@@ -0,0 +1,173 @@
+//! SHA-256 throughput on 1 MiB inputs.
+//!
+//! Run with `cargo bench --bench sha256`. Compares the rolled 64-round loop the portable path
+//! used before the schedule was unrolled, the current portable `compress_blocks`, the
+//! dispatched backend and the one-shot functions, then a batch of small messages hashed in a
+//! loop, with [`sha256_many`] and with the AVX2 lanes forced.
+
+use cryptography::hash::sha256::H256_INIT;
+use cryptography::hash::sha256::backend::{self, Backend};
+use cryptography::hash::sha256::core::compress_blocks;
+use cryptography::hash::sha256::many::MultiBuffer;
+use cryptography::hash::{sha256, sha256_many};
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const INPUT_LEN: usize = 1 << 20;
+const SAMPLES: usize = 31;
+const ITERATIONS: u32 = 8;
+
+/// The portable compression as it was before the round schedule was unrolled, kept only as a
+/// baseline for this benchmark.
+mod rolled {
+    use cryptography::hash::sha256::K256;
+    use cryptography::hash::sha256::computations::{
+        big_sigma0, big_sigma1, ch, maj, small_sigma0, small_sigma1,
+    };
+
+    /// Compresses one block with the 64-iteration loop and its 16-word circular schedule.
+    pub fn compress(block: &[u8; 64], state: &mut [u32; 8]) {
+        let mut w = [0u32; 16];
+        for (slot, bytes) in w.iter_mut().zip(block.as_chunks::<4>().0) {
+            *slot = u32::from_be_bytes(*bytes);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+        for i in 0..64 {
+            if i >= 16 {
+                let w16 = w[(i - 16) & 15];
+                let w15 = w[(i - 15) & 15];
+                let w7 = w[(i - 7) & 15];
+                let w2 = w[(i - 2) & 15];
+
+                w[i & 15] = w16
+                    .wrapping_add(small_sigma0(w15))
+                    .wrapping_add(w7)
+                    .wrapping_add(small_sigma1(w2));
+            }
+
+            let t1 = h
+                .wrapping_add(big_sigma1(e))
+                .wrapping_add(ch(e, f, g))
+                .wrapping_add(w[i & 15])
+                .wrapping_add(K256[i]);
+            let t2 = big_sigma0(a).wrapping_add(maj(a, b, c));
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+
+        for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *s = s.wrapping_add(v);
+        }
+    }
+}
+
+/// Runs `f` twice to warm up, then times `SAMPLES` runs of `ITERATIONS` calls each, prints the
+/// median time per call with the fastest and slowest samples, and returns the median throughput
+/// in MiB/s.
+fn bench(name: &str, bytes: usize, mut f: impl FnMut()) -> f64 {
+    f();
+    f();
+
+    let mut samples: Vec<Duration> = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..ITERATIONS {
+                f();
+            }
+            (start.elapsed() / ITERATIONS).max(Duration::from_nanos(1))
+        })
+        .collect();
+    samples.sort();
+
+    let median = samples[samples.len() / 2];
+    let mib_per_s = bytes as f64 / (1 << 20) as f64 / median.as_secs_f64();
+
+    println!(
+        "{name:<32} {median:>10.3?} / MiB  {mib_per_s:>9.1} MiB/s  [{:.3?} .. {:.3?}]",
+        samples[0],
+        samples[samples.len() - 1]
+    );
+
+    mib_per_s
+}
+
+fn main() {
+    let data: Vec<u8> = (0..INPUT_LEN).map(|i| (i * 7 + 3) as u8).collect();
+    let (blocks, _) = data.as_chunks::<64>();
+
+    let mut expected = H256_INIT;
+    compress_blocks(&mut expected, blocks);
+    let mut baseline = H256_INIT;
+    for block in blocks {
+        rolled::compress(block, &mut baseline);
+    }
+    assert_eq!(
+        baseline, expected,
+        "rolled baseline disagrees with compress_blocks"
+    );
+
+    let before = bench("compress (rolled baseline)", INPUT_LEN, || {
+        let mut state = H256_INIT;
+        for block in black_box(blocks) {
+            rolled::compress(block, &mut state);
+        }
+        black_box(state);
+    });
+
+    let after = bench("compress_blocks (portable)", INPUT_LEN, || {
+        let mut state = H256_INIT;
+        compress_blocks(&mut state, black_box(blocks));
+        black_box(state);
+    });
+
+    println!(
+        "{:<32} {:>34.2}x",
+        "unrolled / rolled (medians)",
+        after / before
+    );
+
+    if Backend::ShaNi.is_available() {
+        bench("compress_blocks (SHA-NI)", INPUT_LEN, || {
+            let mut state = H256_INIT;
+            Backend::ShaNi
+                .compress_blocks(&mut state, black_box(blocks))
+                .unwrap();
+            black_box(state);
+        });
+    }
+
+    bench("sha256 (dispatched)", INPUT_LEN, || {
+        black_box(sha256(black_box(&data)));
+    });
+
+    backend::set_force_portable(true);
+    bench("sha256 (forced portable)", INPUT_LEN, || {
+        black_box(sha256(black_box(&data)));
+    });
+    backend::set_force_portable(false);
+
+    let messages: Vec<&[u8]> = data.chunks(64).collect();
+    bench("sha256 loop (16384 x 64 B)", INPUT_LEN, || {
+        for message in black_box(&messages) {
+            black_box(sha256(message));
+        }
+    });
+    bench("sha256_many (16384 x 64 B)", INPUT_LEN, || {
+        black_box(sha256_many(black_box(&messages)));
+    });
+    if MultiBuffer::Avx2.is_available() {
+        bench("sha256_many AVX2 (16384 x 64 B)", INPUT_LEN, || {
+            black_box(MultiBuffer::Avx2.sha256_many(black_box(&messages)));
+        });
+    }
+}
@@ -0,0 +1,74 @@
+/// Bitcoin hashing helpers.
+///
+/// Ce module regroupe les compositions de hachage utilisées par Bitcoin :
+///
+/// - [`sha256d`] : double SHA-256, pour les identifiants de transaction et de bloc
+/// - [`hash160`] : RIPEMD-160 de SHA-256, pour les adresses P2PKH / P2SH / P2WPKH
+/// - [`txid_hex`] : rendu d'un hachage dans l'ordre d'affichage Bitcoin
+///
+/// Bitcoin affiche les txid et les hachages de bloc octets inversés (le hachage est interprété
+/// comme un entier little-endian), alors que l'affichage de [`U256`] suit l'ordre naturel
+/// des octets.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::bitcoin::{sha256d, txid_hex};
+///
+/// let txid = sha256d(b"raw transaction bytes");
+/// assert_eq!(txid_hex(&txid).len(), 64);
+/// ```
+use crate::hash::ripemd160::ripemd160;
+use crate::hash::sha256::core::sha256;
+
+use crate::primitives::U256;
+
+/// Computes `SHA-256(SHA-256(input))`.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// A [`U256`] holding the digest in natural byte order
+pub fn sha256d(input: &[u8]) -> U256 {
+    sha256(&sha256(input).0)
+}
+
+/// Computes `RIPEMD-160(SHA-256(input))`.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash, typically a serialized public key or script
+///
+/// # Returns
+///
+/// The 20-byte digest
+pub fn hash160(input: &[u8]) -> [u8; 20] {
+    ripemd160(&sha256(input).0)
+}
+
+/// Returns a copy of `hash` with its bytes reversed.
+///
+/// Converts between the internal byte order of a digest and Bitcoin's display order, in both
+/// directions.
+pub fn reverse_bytes(hash: &U256) -> U256 {
+    let mut out = hash.0;
+    out.reverse();
+
+    U256(out)
+}
+
+/// Renders a transaction or block hash as lowercase hex in Bitcoin display order.
+///
+/// # Arguments
+///
+/// * `hash` - The digest as produced by [`sha256d`] (natural byte order)
+///
+/// # Returns
+///
+/// The 64-character hex string shown by block explorers and `bitcoin-cli`
+pub fn txid_hex(hash: &U256) -> String {
+    hash.0.iter().rev().map(|b| format!("{:02x}", b)).collect()
+}
@@ -0,0 +1,378 @@
+/// BLAKE2s hash function (RFC 7693).
+///
+/// Ce module fournit BLAKE2s sur des mots de 32 bits et des blocs de 64 octets, adapté aux
+/// plateformes 32 bits et aux petits messages. La sortie va de 1 à 32 octets ; la clé (jusqu'à
+/// 32 octets), le sel et la personnalisation (jusqu'à 8 octets chacun) se configurent via
+/// [`Blake2sParams`]. [`blake2s256`] renvoie un [`U256`], comme [`sha256`](crate::hash::sha256()).
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::blake2::blake2s::{Blake2s256, blake2s256};
+///
+/// let mut hasher = Blake2s256::new();
+/// hasher.update(b"abc");
+/// assert_eq!(hasher.finalize(), blake2s256(b"abc"));
+/// ```
+use crate::hash::blake2::{Blake2Error, SIGMA};
+use crate::hash::sha256::H256_INIT;
+
+use crate::primitives::U256;
+
+/// Block size of BLAKE2s in bytes.
+pub const BLAKE2S_BLOCK_LEN: usize = 64;
+
+/// Maximum digest length of BLAKE2s in bytes.
+pub const BLAKE2S_MAX_OUT_LEN: usize = 32;
+
+/// Maximum key length of BLAKE2s in bytes.
+pub const BLAKE2S_MAX_KEY_LEN: usize = 32;
+
+/// Salt and personalization length of BLAKE2s in bytes.
+pub const BLAKE2S_SALT_LEN: usize = 8;
+
+/// The BLAKE2s mixing function G.
+#[inline(always)]
+fn g(v: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(12);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(8);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(7);
+}
+
+/// Compresses one 64-byte block into the chaining value.
+///
+/// # Arguments
+///
+/// * `h` - The chaining value, updated in-place
+/// * `block` - The message block
+/// * `t` - Total number of bytes hashed so far, including this block
+/// * `last` - Whether this is the final block
+pub fn compress(h: &mut [u32; 8], block: &[u8; 64], t: u64, last: bool) {
+    let mut m = [0u32; 16];
+    for (i, word) in m.iter_mut().enumerate() {
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(&block[i * 4..i * 4 + 4]);
+        *word = u32::from_le_bytes(bytes);
+    }
+
+    let mut v = [0u32; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&H256_INIT);
+    v[12] ^= t as u32;
+    v[13] ^= (t >> 32) as u32;
+    if last {
+        v[14] = !v[14];
+    }
+
+    for s in &SIGMA {
+        g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+        g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+        g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+        g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+        g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+        g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+        g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+        g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+    }
+
+    for i in 0..8 {
+        h[i] ^= v[i] ^ v[i + 8];
+    }
+}
+
+/// XORs the sequential-mode parameter block into the IV.
+///
+/// Encodes digest length, key length, fanout = 1 and depth = 1 in the first word, then the
+/// salt and personalization as little-endian words.
+fn initial_state(
+    out_len: usize,
+    key_len: usize,
+    salt: &[u8; BLAKE2S_SALT_LEN],
+    personal: &[u8; BLAKE2S_SALT_LEN],
+) -> [u32; 8] {
+    let mut h = H256_INIT;
+    h[0] ^= 0x0101_0000 ^ ((key_len as u32) << 8) ^ out_len as u32;
+
+    for (i, chunk) in salt
+        .chunks_exact(4)
+        .chain(personal.chunks_exact(4))
+        .enumerate()
+    {
+        let mut word = [0u8; 4];
+        word.copy_from_slice(chunk);
+        h[4 + i] ^= u32::from_le_bytes(word);
+    }
+
+    h
+}
+
+/// Parameter block for BLAKE2s, validated by [`Blake2sParams::build`].
+#[derive(Clone, Debug)]
+pub struct Blake2sParams {
+    out_len: usize,
+    key: Vec<u8>,
+    salt: Vec<u8>,
+    personal: Vec<u8>,
+}
+
+impl Blake2sParams {
+    /// Creates parameters for an unkeyed 32-byte digest.
+    pub fn new() -> Self {
+        Self {
+            out_len: BLAKE2S_MAX_OUT_LEN,
+            key: Vec::new(),
+            salt: Vec::new(),
+            personal: Vec::new(),
+        }
+    }
+
+    /// Sets the digest length in bytes (1 to 32).
+    pub fn out_len(mut self, out_len: usize) -> Self {
+        self.out_len = out_len;
+        self
+    }
+
+    /// Sets the key (up to 32 bytes), turning the hash into a MAC.
+    pub fn key(mut self, key: &[u8]) -> Self {
+        self.key = key.to_vec();
+        self
+    }
+
+    /// Sets the salt (up to 8 bytes, zero-padded).
+    pub fn salt(mut self, salt: &[u8]) -> Self {
+        self.salt = salt.to_vec();
+        self
+    }
+
+    /// Sets the personalization string (up to 8 bytes, zero-padded).
+    pub fn personal(mut self, personal: &[u8]) -> Self {
+        self.personal = personal.to_vec();
+        self
+    }
+
+    /// Validates the parameters and returns a ready-to-use hasher.
+    pub fn build(&self) -> Result<Blake2s, Blake2Error> {
+        if self.out_len == 0 || self.out_len > BLAKE2S_MAX_OUT_LEN {
+            return Err(Blake2Error::InvalidOutputLength(self.out_len));
+        }
+        if self.key.len() > BLAKE2S_MAX_KEY_LEN {
+            return Err(Blake2Error::KeyTooLong(self.key.len()));
+        }
+        if self.salt.len() > BLAKE2S_SALT_LEN {
+            return Err(Blake2Error::SaltTooLong(self.salt.len()));
+        }
+        if self.personal.len() > BLAKE2S_SALT_LEN {
+            return Err(Blake2Error::PersonalTooLong(self.personal.len()));
+        }
+
+        let mut salt = [0u8; BLAKE2S_SALT_LEN];
+        salt[..self.salt.len()].copy_from_slice(&self.salt);
+        let mut personal = [0u8; BLAKE2S_SALT_LEN];
+        personal[..self.personal.len()].copy_from_slice(&self.personal);
+
+        let h = initial_state(self.out_len, self.key.len(), &salt, &personal);
+        let mut hasher = Blake2s::with_state(h, self.out_len);
+
+        if !self.key.is_empty() {
+            let mut block = [0u8; BLAKE2S_BLOCK_LEN];
+            block[..self.key.len()].copy_from_slice(&self.key);
+
+            hasher.key_block = Some(block);
+            hasher.buffer = block;
+            hasher.buffer_len = BLAKE2S_BLOCK_LEN;
+        }
+
+        Ok(hasher)
+    }
+}
+
+impl Default for Blake2sParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Streaming BLAKE2s state with a variable output length.
+///
+/// The last block is kept buffered until [`Blake2s::finalize`], since BLAKE2 flags the final
+/// compression differently.
+#[derive(Clone)]
+pub struct Blake2s {
+    h: [u32; 8],
+    buffer: [u8; BLAKE2S_BLOCK_LEN],
+    buffer_len: usize,
+    t: u64,
+    out_len: usize,
+    initial: [u32; 8],
+    key_block: Option<[u8; BLAKE2S_BLOCK_LEN]>,
+}
+
+impl Blake2s {
+    /// Creates a hasher from an already parameterized chaining value.
+    fn with_state(h: [u32; 8], out_len: usize) -> Self {
+        Self {
+            h,
+            buffer: [0u8; BLAKE2S_BLOCK_LEN],
+            buffer_len: 0,
+            t: 0,
+            out_len,
+            initial: h,
+            key_block: None,
+        }
+    }
+
+    /// Creates an unkeyed hasher producing `out_len` bytes.
+    pub fn new(out_len: usize) -> Result<Self, Blake2Error> {
+        Blake2sParams::new().out_len(out_len).build()
+    }
+
+    /// Creates a keyed hasher (MAC) producing `out_len` bytes.
+    pub fn new_keyed(key: &[u8], out_len: usize) -> Result<Self, Blake2Error> {
+        Blake2sParams::new().out_len(out_len).key(key).build()
+    }
+
+    /// Returns the digest length in bytes.
+    pub fn out_len(&self) -> usize {
+        self.out_len
+    }
+
+    /// Absorbs `input` into the hash state.
+    pub fn update(&mut self, input: &[u8]) {
+        let mut input = input;
+
+        while !input.is_empty() {
+            if self.buffer_len == BLAKE2S_BLOCK_LEN {
+                // More data follows, so the buffered block is not the last one
+                self.t += BLAKE2S_BLOCK_LEN as u64;
+                compress(&mut self.h, &self.buffer, self.t, false);
+                self.buffer_len = 0;
+            }
+
+            let take = (BLAKE2S_BLOCK_LEN - self.buffer_len).min(input.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&input[..take]);
+            self.buffer_len += take;
+            input = &input[take..];
+        }
+    }
+
+    /// Writes the digest into `out`, which must be exactly [`Blake2s::out_len`] bytes long.
+    fn finalize_into(mut self, out: &mut [u8]) {
+        self.t += self.buffer_len as u64;
+        self.buffer[self.buffer_len..].fill(0);
+        compress(&mut self.h, &self.buffer, self.t, true);
+
+        let mut full = [0u8; 32];
+        for (i, word) in self.h.iter().enumerate() {
+            full[i * 4..i * 4 + 4].copy_from_slice(&word.to_le_bytes());
+        }
+
+        out.copy_from_slice(&full[..out.len()]);
+    }
+
+    /// Consumes the hasher and returns the digest.
+    pub fn finalize(self) -> Vec<u8> {
+        let mut out = vec![0u8; self.out_len];
+        self.finalize_into(&mut out);
+
+        out
+    }
+
+    /// Resets the hasher to its initial state, keeping the parameters and key.
+    pub fn reset(&mut self) {
+        self.h = self.initial;
+        self.t = 0;
+        self.buffer = [0u8; BLAKE2S_BLOCK_LEN];
+        self.buffer_len = 0;
+
+        if let Some(block) = self.key_block {
+            self.buffer = block;
+            self.buffer_len = BLAKE2S_BLOCK_LEN;
+        }
+    }
+}
+
+/// Streaming unkeyed BLAKE2s-256 state.
+#[derive(Clone)]
+pub struct Blake2s256 {
+    inner: Blake2s,
+}
+
+impl Blake2s256 {
+    /// Creates an empty hasher.
+    pub fn new() -> Self {
+        let zero = [0u8; BLAKE2S_SALT_LEN];
+        let h = initial_state(BLAKE2S_MAX_OUT_LEN, 0, &zero, &zero);
+
+        Self {
+            inner: Blake2s::with_state(h, BLAKE2S_MAX_OUT_LEN),
+        }
+    }
+
+    /// Absorbs `input` into the hash state.
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    /// Consumes the hasher and returns the BLAKE2s-256 digest of everything absorbed.
+    pub fn finalize(self) -> U256 {
+        let mut out = [0u8; 32];
+        self.inner.finalize_into(&mut out);
+
+        U256(out)
+    }
+
+    /// Resets the hasher to its initial state, discarding any absorbed data.
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+impl Default for Blake2s256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes an unkeyed BLAKE2s digest of `out_len` bytes.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash
+/// * `out_len` - Digest length in bytes (1 to 32)
+pub fn blake2s(input: &[u8], out_len: usize) -> Result<Vec<u8>, Blake2Error> {
+    let mut hasher = Blake2s::new(out_len)?;
+    hasher.update(input);
+
+    Ok(hasher.finalize())
+}
+
+/// Computes a keyed BLAKE2s digest (MAC) of `out_len` bytes.
+///
+/// # Arguments
+///
+/// * `key` - The secret key (up to 32 bytes)
+/// * `input` - The input data to authenticate
+/// * `out_len` - Digest length in bytes (1 to 32)
+pub fn blake2s_keyed(key: &[u8], input: &[u8], out_len: usize) -> Result<Vec<u8>, Blake2Error> {
+    let mut hasher = Blake2s::new_keyed(key, out_len)?;
+    hasher.update(input);
+
+    Ok(hasher.finalize())
+}
+
+/// Computes the unkeyed BLAKE2s-256 hash of the input data.
+///
+/// # Returns
+///
+/// A [`U256`] containing the 256-bit hash output
+pub fn blake2s256(input: &[u8]) -> U256 {
+    let mut hasher = Blake2s256::new();
+    hasher.update(input);
+
+    hasher.finalize()
+}
@@ -0,0 +1,77 @@
+/// BLAKE2 cryptographic hash implementation.
+///
+/// Ce module fournit BLAKE2b (mots de 64 bits, sorties de 1 à 64 octets) et BLAKE2s (mots de
+/// 32 bits, sorties de 1 à 32 octets) conformes à la RFC 7693, avec les paramètres optionnels
+/// de clé, de sel et de personnalisation. Les vecteurs d'initialisation sont ceux de SHA-512
+/// ([`H512_INIT`](crate::hash::sha512::H512_INIT)) et SHA-256
+/// ([`H256_INIT`](crate::hash::sha256::H256_INIT)).
+///
+/// # Constantes
+///
+/// - [`SIGMA`] : permutations des mots du message pour chaque tour
+///
+/// # Modules
+///
+/// - [`blake2b`] : BLAKE2b, paramètres et états incrémentaux
+/// - [`blake2s`] : BLAKE2s, paramètres et états incrémentaux
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::blake2::blake2b::Blake2bParams;
+///
+/// let mut hasher = Blake2bParams::new()
+///     .out_len(32)
+///     .key(b"secret")
+///     .personal(b"Nebula content")
+///     .build()
+///     .unwrap();
+/// hasher.update(b"object bytes");
+/// assert_eq!(hasher.finalize().len(), 32);
+/// ```
+pub mod blake2b;
+pub mod blake2s;
+
+use std::fmt::{Display, Formatter, Result};
+
+/// Message word permutations, one row per round (BLAKE2b reuses rows 0 and 1 for rounds 10 and 11).
+pub const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// Errors returned when BLAKE2 parameters are out of range.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Blake2Error {
+    /// The requested digest length is zero or above the variant maximum.
+    InvalidOutputLength(usize),
+    /// The key is longer than the variant maximum.
+    KeyTooLong(usize),
+    /// The salt is longer than the variant maximum.
+    SaltTooLong(usize),
+    /// The personalization string is longer than the variant maximum.
+    PersonalTooLong(usize),
+}
+
+impl Display for Blake2Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Blake2Error::InvalidOutputLength(len) => write!(f, "invalid output length: {len}"),
+            Blake2Error::KeyTooLong(len) => write!(f, "key too long: {len} bytes"),
+            Blake2Error::SaltTooLong(len) => write!(f, "salt too long: {len} bytes"),
+            Blake2Error::PersonalTooLong(len) => {
+                write!(f, "personalization too long: {len} bytes")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Blake2Error {}
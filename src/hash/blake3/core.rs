@@ -0,0 +1,172 @@
+/// BLAKE3 compression function and output nodes.
+///
+/// Ce module contient la compression BLAKE3 (7 tours de la fonction G de BLAKE2s avec une
+/// permutation fixe des mots du message) et le type [`Output`], qui représente un nœud encore
+/// non compressé : il peut produire soit une valeur de chaînage, soit la sortie racine.
+///
+/// # Références
+///
+/// - BLAKE3 specification, §2.2 (compression function)
+use crate::hash::blake3::{BLOCK_LEN, MSG_PERMUTATION, PARENT, ROOT};
+use crate::hash::sha256::H256_INIT;
+
+/// The BLAKE3 mixing function G (same rotations as BLAKE2s).
+#[inline(always)]
+fn g(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, mx: u32, my: u32) {
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(mx);
+    state[d] = (state[d] ^ state[a]).rotate_right(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(12);
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(my);
+    state[d] = (state[d] ^ state[a]).rotate_right(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(7);
+}
+
+/// One round: G over the four columns, then over the four diagonals.
+#[inline(always)]
+fn round(state: &mut [u32; 16], m: &[u32; 16]) {
+    g(state, 0, 4, 8, 12, m[0], m[1]);
+    g(state, 1, 5, 9, 13, m[2], m[3]);
+    g(state, 2, 6, 10, 14, m[4], m[5]);
+    g(state, 3, 7, 11, 15, m[6], m[7]);
+    g(state, 0, 5, 10, 15, m[8], m[9]);
+    g(state, 1, 6, 11, 12, m[10], m[11]);
+    g(state, 2, 7, 8, 13, m[12], m[13]);
+    g(state, 3, 4, 9, 14, m[14], m[15]);
+}
+
+/// Compresses one block and returns the full 16-word state.
+///
+/// # Arguments
+///
+/// * `cv` - Input chaining value
+/// * `block` - Message block as 16 little-endian words
+/// * `counter` - Chunk counter (or output block counter for the root)
+/// * `block_len` - Number of meaningful bytes in the block
+/// * `flags` - Domain separation flags
+pub fn compress(
+    cv: &[u32; 8],
+    block: &[u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+) -> [u32; 16] {
+    let mut state = [
+        cv[0],
+        cv[1],
+        cv[2],
+        cv[3],
+        cv[4],
+        cv[5],
+        cv[6],
+        cv[7],
+        H256_INIT[0],
+        H256_INIT[1],
+        H256_INIT[2],
+        H256_INIT[3],
+        counter as u32,
+        (counter >> 32) as u32,
+        block_len,
+        flags,
+    ];
+
+    let mut m = *block;
+    for r in 0..7 {
+        round(&mut state, &m);
+
+        if r < 6 {
+            let mut permuted = [0u32; 16];
+            for (slot, &src) in permuted.iter_mut().zip(MSG_PERMUTATION.iter()) {
+                *slot = m[src];
+            }
+            m = permuted;
+        }
+    }
+
+    for i in 0..8 {
+        state[i] ^= state[i + 8];
+        state[i + 8] ^= cv[i];
+    }
+
+    state
+}
+
+/// Reads a 64-byte block as 16 little-endian words.
+pub fn words_from_block(block: &[u8; BLOCK_LEN]) -> [u32; 16] {
+    let mut words = [0u32; 16];
+
+    for (word, chunk) in words.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+
+    words
+}
+
+/// Returns the first 8 words of a compression output.
+#[inline(always)]
+pub fn first_8_words(state: [u32; 16]) -> [u32; 8] {
+    let mut out = [0u32; 8];
+    out.copy_from_slice(&state[..8]);
+
+    out
+}
+
+/// A node whose final compression has not been performed yet.
+///
+/// The same node yields either a chaining value (when it has a parent) or the root output
+/// (when it is the top of the tree), depending on whether the `ROOT` flag is set.
+#[derive(Clone, Copy)]
+pub struct Output {
+    pub(crate) input_cv: [u32; 8],
+    pub(crate) block: [u32; 16],
+    pub(crate) counter: u64,
+    pub(crate) block_len: u32,
+    pub(crate) flags: u32,
+}
+
+impl Output {
+    /// Returns the chaining value of this (non-root) node.
+    pub fn chaining_value(&self) -> [u32; 8] {
+        first_8_words(compress(
+            &self.input_cv,
+            &self.block,
+            self.counter,
+            self.block_len,
+            self.flags,
+        ))
+    }
+
+    /// Returns output block number `block_counter` of this root node.
+    pub fn root_output_block(&self, block_counter: u64) -> [u8; BLOCK_LEN] {
+        let words = compress(
+            &self.input_cv,
+            &self.block,
+            block_counter,
+            self.block_len,
+            self.flags | ROOT,
+        );
+
+        let mut out = [0u8; BLOCK_LEN];
+        for (chunk, word) in out.chunks_exact_mut(4).zip(words.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+
+        out
+    }
+}
+
+/// Builds the output node of a parent whose children have the given chaining values.
+pub fn parent_output(left: &[u32; 8], right: &[u32; 8], key: &[u32; 8], flags: u32) -> Output {
+    let mut block = [0u32; 16];
+    block[..8].copy_from_slice(left);
+    block[8..].copy_from_slice(right);
+
+    Output {
+        input_cv: *key,
+        block,
+        counter: 0,
+        block_len: BLOCK_LEN as u32,
+        flags: PARENT | flags,
+    }
+}
@@ -0,0 +1,352 @@
+/// Incremental BLAKE3 hasher.
+///
+/// Ce module fournit [`Blake3`], qui hache les chunks au fil de l'eau et maintient une pile de
+/// valeurs de chaînage : après chaque chunk complet, les sous-arbres de même taille sont
+/// fusionnés en nœuds parents. La pile contient au plus un sous-arbre par bit du nombre de
+/// chunks, soit 54 entrées pour 2⁶⁴ octets.
+///
+/// [`Blake3::update_parallel`] répartit les chunks complets d'une grande entrée sur plusieurs
+/// threads ; le résultat est identique à celui de [`Blake3::update`].
+use crate::hash::blake3::core::{Output, compress, first_8_words, parent_output, words_from_block};
+use crate::hash::blake3::{
+    BLOCK_LEN, CHUNK_END, CHUNK_LEN, CHUNK_START, DERIVE_KEY_CONTEXT, DERIVE_KEY_MATERIAL, KEY_LEN,
+    KEYED_HASH,
+};
+use crate::hash::sha256::H256_INIT;
+
+use crate::primitives::U256;
+
+use std::thread;
+
+/// Minimum input size for [`Blake3::update_parallel`] to spread work across threads.
+pub const PARALLEL_THRESHOLD: usize = 128 * CHUNK_LEN;
+
+/// Hashing state of the chunk currently being filled.
+#[derive(Clone)]
+struct ChunkState {
+    cv: [u32; 8],
+    chunk_counter: u64,
+    block: [u8; BLOCK_LEN],
+    block_len: usize,
+    blocks_compressed: usize,
+    flags: u32,
+}
+
+impl ChunkState {
+    fn new(key: &[u32; 8], chunk_counter: u64, flags: u32) -> Self {
+        Self {
+            cv: *key,
+            chunk_counter,
+            block: [0u8; BLOCK_LEN],
+            block_len: 0,
+            blocks_compressed: 0,
+            flags,
+        }
+    }
+
+    /// Number of bytes absorbed into this chunk so far.
+    fn len(&self) -> usize {
+        BLOCK_LEN * self.blocks_compressed + self.block_len
+    }
+
+    fn start_flag(&self) -> u32 {
+        if self.blocks_compressed == 0 {
+            CHUNK_START
+        } else {
+            0
+        }
+    }
+
+    fn update(&mut self, input: &[u8]) {
+        let mut input = input;
+
+        while !input.is_empty() {
+            // Only compress a full block once more input arrives: the last block is special
+            if self.block_len == BLOCK_LEN {
+                let words = words_from_block(&self.block);
+                self.cv = first_8_words(compress(
+                    &self.cv,
+                    &words,
+                    self.chunk_counter,
+                    BLOCK_LEN as u32,
+                    self.flags | self.start_flag(),
+                ));
+                self.blocks_compressed += 1;
+                self.block = [0u8; BLOCK_LEN];
+                self.block_len = 0;
+            }
+
+            let take = (BLOCK_LEN - self.block_len).min(input.len());
+            self.block[self.block_len..self.block_len + take].copy_from_slice(&input[..take]);
+            self.block_len += take;
+            input = &input[take..];
+        }
+    }
+
+    fn output(&self) -> Output {
+        Output {
+            input_cv: self.cv,
+            block: words_from_block(&self.block),
+            counter: self.chunk_counter,
+            block_len: self.block_len as u32,
+            flags: self.flags | self.start_flag() | CHUNK_END,
+        }
+    }
+}
+
+/// Reads a 32-byte key as eight little-endian words.
+fn key_words(key: &[u8; KEY_LEN]) -> [u32; 8] {
+    let mut words = [0u32; 8];
+
+    for (word, chunk) in words.iter_mut().zip(key.chunks_exact(4)) {
+        *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+
+    words
+}
+
+/// Computes the chaining value of one complete chunk.
+fn chunk_cv(key: &[u32; 8], chunk: &[u8], chunk_counter: u64, flags: u32) -> [u32; 8] {
+    let mut state = ChunkState::new(key, chunk_counter, flags);
+    state.update(chunk);
+
+    state.output().chaining_value()
+}
+
+/// Streaming BLAKE3 state.
+#[derive(Clone)]
+pub struct Blake3 {
+    chunk_state: ChunkState,
+    key: [u32; 8],
+    cv_stack: Vec<[u32; 8]>,
+    flags: u32,
+}
+
+impl Blake3 {
+    fn with_key_and_flags(key: [u32; 8], flags: u32) -> Self {
+        Self {
+            chunk_state: ChunkState::new(&key, 0, flags),
+            key,
+            cv_stack: Vec::with_capacity(54),
+            flags,
+        }
+    }
+
+    /// Creates a hasher for the default hash mode.
+    pub fn new() -> Self {
+        Self::with_key_and_flags(H256_INIT, 0)
+    }
+
+    /// Creates a hasher for the keyed hash mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A uniformly random 32-byte key
+    pub fn new_keyed(key: &[u8; KEY_LEN]) -> Self {
+        Self::with_key_and_flags(key_words(key), KEYED_HASH)
+    }
+
+    /// Creates a hasher for the key derivation mode.
+    ///
+    /// The context string is hashed first into a context key, which then keys the hashing of
+    /// the key material.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - A hardcoded, globally unique, application-specific string
+    pub fn new_derive_key(context: &str) -> Self {
+        let mut context_hasher = Self::with_key_and_flags(H256_INIT, DERIVE_KEY_CONTEXT);
+        context_hasher.update(context.as_bytes());
+
+        let context_key = context_hasher.finalize();
+
+        Self::with_key_and_flags(key_words(&context_key.0), DERIVE_KEY_MATERIAL)
+    }
+
+    /// Pushes the chaining value of a completed chunk, merging equal-sized subtrees.
+    ///
+    /// `total_chunks` is the number of chunks hashed so far, including this one; each trailing
+    /// zero bit means a subtree on the stack is complete and can be merged into its parent.
+    fn add_chunk_chaining_value(&mut self, mut new_cv: [u32; 8], mut total_chunks: u64) {
+        while total_chunks & 1 == 0 {
+            if let Some(left) = self.cv_stack.pop() {
+                new_cv = parent_output(&left, &new_cv, &self.key, self.flags).chaining_value();
+            }
+            total_chunks >>= 1;
+        }
+
+        self.cv_stack.push(new_cv);
+    }
+
+    /// Moves a full chunk out of the current chunk state, as more input follows it.
+    fn flush_full_chunk(&mut self) {
+        let cv = self.chunk_state.output().chaining_value();
+        let total_chunks = self.chunk_state.chunk_counter + 1;
+
+        self.add_chunk_chaining_value(cv, total_chunks);
+        self.chunk_state = ChunkState::new(&self.key, total_chunks, self.flags);
+    }
+
+    /// Absorbs `input` into the hash state.
+    pub fn update(&mut self, input: &[u8]) {
+        let mut input = input;
+
+        while !input.is_empty() {
+            if self.chunk_state.len() == CHUNK_LEN {
+                self.flush_full_chunk();
+            }
+
+            let take = (CHUNK_LEN - self.chunk_state.len()).min(input.len());
+            self.chunk_state.update(&input[..take]);
+            input = &input[take..];
+        }
+    }
+
+    /// Absorbs `input`, hashing its whole chunks on several threads when it is large.
+    ///
+    /// Inputs smaller than [`PARALLEL_THRESHOLD`] are handled by [`Blake3::update`]. The
+    /// result never depends on the number of threads.
+    pub fn update_parallel(&mut self, input: &[u8]) {
+        let mut input = input;
+
+        // Finish the partial chunk serially so the parallel part starts on a chunk boundary
+        let pending = self.chunk_state.len();
+        if pending != 0 && pending != CHUNK_LEN {
+            let take = (CHUNK_LEN - pending).min(input.len());
+            self.update(&input[..take]);
+            input = &input[take..];
+        }
+
+        if input.len() < PARALLEL_THRESHOLD {
+            self.update(input);
+            return;
+        }
+
+        if self.chunk_state.len() == CHUNK_LEN {
+            self.flush_full_chunk();
+        }
+
+        // Keep the final chunk (1..=CHUNK_LEN bytes) for the serial path: it may be the root
+        let count = (input.len() - 1) / CHUNK_LEN;
+        let (chunks, rest) = input.split_at(count * CHUNK_LEN);
+        let first = self.chunk_state.chunk_counter;
+
+        let mut cvs = vec![[0u32; 8]; count];
+        let workers = thread::available_parallelism().map_or(1, |n| n.get());
+        let per_worker = count.div_ceil(workers);
+        let (key, flags) = (self.key, self.flags);
+
+        thread::scope(|scope| {
+            for (w, (inputs, outputs)) in chunks
+                .chunks(per_worker * CHUNK_LEN)
+                .zip(cvs.chunks_mut(per_worker))
+                .enumerate()
+            {
+                let base = first + (w * per_worker) as u64;
+
+                scope.spawn(move || {
+                    for (i, (chunk, cv)) in inputs
+                        .chunks_exact(CHUNK_LEN)
+                        .zip(outputs.iter_mut())
+                        .enumerate()
+                    {
+                        *cv = chunk_cv(&key, chunk, base + i as u64, flags);
+                    }
+                });
+            }
+        });
+
+        for (i, cv) in cvs.into_iter().enumerate() {
+            self.add_chunk_chaining_value(cv, first + i as u64 + 1);
+        }
+
+        self.chunk_state = ChunkState::new(&self.key, first + count as u64, self.flags);
+        self.update(rest);
+    }
+
+    /// Builds the root node by merging the current chunk with every subtree on the stack.
+    fn root_output(&self) -> Output {
+        let mut output = self.chunk_state.output();
+
+        for left in self.cv_stack.iter().rev() {
+            output = parent_output(left, &output.chaining_value(), &self.key, self.flags);
+        }
+
+        output
+    }
+
+    /// Consumes the hasher and returns the default 32-byte output.
+    ///
+    /// # Returns
+    ///
+    /// A [`U256`] holding the output bytes in order
+    pub fn finalize(self) -> U256 {
+        let block = self.root_output().root_output_block(0);
+
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&block[..32]);
+
+        U256(out)
+    }
+
+    /// Consumes the hasher and returns a reader over the extendable output.
+    ///
+    /// The first 32 bytes of the stream equal [`Blake3::finalize`].
+    pub fn finalize_xof(self) -> OutputReader {
+        OutputReader {
+            output: self.root_output(),
+            position: 0,
+        }
+    }
+
+    /// Resets the hasher to its initial state, keeping the mode and key.
+    pub fn reset(&mut self) {
+        self.chunk_state = ChunkState::new(&self.key, 0, self.flags);
+        self.cv_stack.clear();
+    }
+}
+
+impl Default for Blake3 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reader over the BLAKE3 extendable output.
+///
+/// Each 64-byte output block is an independent compression of the root node with an
+/// increasing counter, so the reader can also jump with [`OutputReader::set_position`].
+#[derive(Clone)]
+pub struct OutputReader {
+    output: Output,
+    position: u64,
+}
+
+impl OutputReader {
+    /// Fills `out` with the next `out.len()` bytes of output.
+    pub fn squeeze(&mut self, out: &mut [u8]) {
+        let mut out = out;
+
+        while !out.is_empty() {
+            let block = self
+                .output
+                .root_output_block(self.position / BLOCK_LEN as u64);
+            let offset = (self.position % BLOCK_LEN as u64) as usize;
+            let take = (BLOCK_LEN - offset).min(out.len());
+
+            out[..take].copy_from_slice(&block[offset..offset + take]);
+            out = &mut out[take..];
+            self.position += take as u64;
+        }
+    }
+
+    /// Returns the current position in the output stream.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Moves to an arbitrary position in the output stream.
+    pub fn set_position(&mut self, position: u64) {
+        self.position = position;
+    }
+}
@@ -0,0 +1,105 @@
+/// BLAKE3 cryptographic hash implementation.
+///
+/// Ce module fournit BLAKE3 : l'entrée est découpée en chunks de 1024 octets, chacun haché
+/// indépendamment, puis les valeurs de chaînage sont fusionnées deux à deux dans un arbre
+/// binaire. Cette structure permet de calculer et vérifier de gros objets en parallèle, ce que
+/// la construction Merkle–Damgård série de [`sha256`](crate::hash::sha256()) ne permet pas.
+///
+/// # Modes
+///
+/// - [`blake3`] / [`Blake3::new`] : hachage simple
+/// - [`keyed_hash`] / [`Blake3::new_keyed`] : MAC avec une clé de 32 octets
+/// - [`derive_key`] / [`Blake3::new_derive_key`] : dérivation de clé à partir d'une chaîne de contexte
+/// - [`Blake3::finalize_xof`] : sortie extensible de longueur arbitraire
+/// - [`Blake3::update_parallel`] : hachage multi-thread des gros volumes
+///
+/// # Modules
+///
+/// - [`core`] : fonction de compression et nœuds de sortie
+/// - [`hasher`] : état de chunk, pile des valeurs de chaînage et hasher incrémental
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::blake3::{Blake3, blake3};
+///
+/// let mut hasher = Blake3::new();
+/// hasher.update(b"hello ");
+/// hasher.update(b"world");
+///
+/// assert_eq!(hasher.finalize(), blake3(b"hello world"));
+/// ```
+pub mod core;
+pub mod hasher;
+
+pub use hasher::{Blake3, OutputReader};
+
+use crate::primitives::U256;
+
+/// Default output length in bytes.
+pub const OUT_LEN: usize = 32;
+
+/// Key length in bytes for the keyed mode.
+pub const KEY_LEN: usize = 32;
+
+/// Block length in bytes.
+pub const BLOCK_LEN: usize = 64;
+
+/// Chunk length in bytes.
+pub const CHUNK_LEN: usize = 1024;
+
+/// Domain flag: first block of a chunk.
+pub const CHUNK_START: u32 = 1 << 0;
+/// Domain flag: last block of a chunk.
+pub const CHUNK_END: u32 = 1 << 1;
+/// Domain flag: parent node.
+pub const PARENT: u32 = 1 << 2;
+/// Domain flag: root node.
+pub const ROOT: u32 = 1 << 3;
+/// Domain flag: keyed hash mode.
+pub const KEYED_HASH: u32 = 1 << 4;
+/// Domain flag: hashing the context string in derive_key mode.
+pub const DERIVE_KEY_CONTEXT: u32 = 1 << 5;
+/// Domain flag: hashing the key material in derive_key mode.
+pub const DERIVE_KEY_MATERIAL: u32 = 1 << 6;
+
+/// Message word permutation applied between rounds.
+pub const MSG_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+/// Computes the BLAKE3 hash of the input data.
+///
+/// # Returns
+///
+/// A [`U256`] containing the default 256-bit output
+pub fn blake3(input: &[u8]) -> U256 {
+    let mut hasher = Blake3::new();
+    hasher.update(input);
+
+    hasher.finalize()
+}
+
+/// Computes the keyed BLAKE3 hash (MAC) of the input data.
+///
+/// # Arguments
+///
+/// * `key` - A uniformly random 32-byte key
+/// * `input` - The data to authenticate
+pub fn keyed_hash(key: &[u8; KEY_LEN], input: &[u8]) -> U256 {
+    let mut hasher = Blake3::new_keyed(key);
+    hasher.update(input);
+
+    hasher.finalize()
+}
+
+/// Derives a 32-byte key from `key_material` in the domain given by `context`.
+///
+/// # Arguments
+///
+/// * `context` - A hardcoded, globally unique, application-specific string
+/// * `key_material` - The secret input keying material
+pub fn derive_key(context: &str, key_material: &[u8]) -> [u8; OUT_LEN] {
+    let mut hasher = Blake3::new_derive_key(context);
+    hasher.update(key_material);
+
+    hasher.finalize().0
+}
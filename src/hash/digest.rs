@@ -0,0 +1,187 @@
+/// Common interface of the fixed-output hash functions.
+///
+/// Ce module définit [`Digest`], le trait implémenté par chaque hasher à sortie fixe de
+/// [`hash`](crate::hash), et [`DynDigest`], sa variante utilisable comme objet de trait
+/// (`Box<dyn DynDigest>`) lorsque la fonction de hachage est choisie à l'exécution.
+///
+/// Le code générique sur la fonction de hachage (HMAC, arbres de Merkle, KDF) s'écrit
+/// sur `D: Digest` ; les méthodes inhérentes de chaque type restent disponibles.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::digest::{Digest, DynDigest};
+/// use cryptography::hash::{Sha256, Sha512, sha256};
+///
+/// fn fingerprint<D: Digest>(data: &[u8]) -> D::Output {
+///     D::digest(data)
+/// }
+///
+/// assert_eq!(fingerprint::<Sha256>(b"abc").to_u256(), sha256(b"abc"));
+///
+/// let mut hashers: Vec<Box<dyn DynDigest>> = vec![Box::new(Sha256::new()), Box::new(Sha512::new())];
+/// for hasher in hashers.iter_mut() {
+///     hasher.update(b"abc");
+///     assert_eq!(hasher.finalize_reset().len(), hasher.output_size());
+/// }
+/// ```
+use crate::hash::blake2::blake2b::Blake2b512;
+use crate::hash::blake2::blake2s::Blake2s256;
+use crate::hash::blake3::Blake3;
+use crate::hash::keccak256::Keccak256;
+use crate::hash::output::Output;
+use crate::hash::ripemd160::Ripemd160;
+use crate::hash::sha3::hasher::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+use crate::hash::sha3::{SHA3_224_RATE, SHA3_256_RATE, SHA3_384_RATE, SHA3_512_RATE};
+use crate::hash::sha224::Sha224;
+use crate::hash::sha256::hasher::Sha256;
+use crate::hash::sha512::hasher::{Sha384, Sha512, Sha512_224, Sha512_256};
+
+use std::fmt::{Debug, Display, LowerHex};
+use std::hash::Hash;
+
+/// A streaming hash function with a fixed output size.
+pub trait Digest: Clone {
+    /// Digest type returned by [`Digest::finalize`].
+    ///
+    /// Always an [`Output`] of [`OUTPUT_SIZE`](Digest::OUTPUT_SIZE) bytes, whose equality is
+    /// constant-time.
+    type Output: AsRef<[u8]> + Copy + Eq + Hash + Debug + Display + LowerHex;
+
+    /// Output size in bytes.
+    const OUTPUT_SIZE: usize;
+
+    /// Internal block size in bytes (the rate for sponge constructions).
+    const BLOCK_SIZE: usize;
+
+    /// Creates a hasher in its initial state.
+    fn new() -> Self;
+
+    /// Absorbs `input` into the hash state.
+    fn update(&mut self, input: &[u8]);
+
+    /// Consumes the hasher and returns the digest of everything absorbed.
+    fn finalize(self) -> Self::Output;
+
+    /// Returns the digest and resets the hasher to its initial state.
+    fn finalize_reset(&mut self) -> Self::Output {
+        std::mem::replace(self, Self::new()).finalize()
+    }
+
+    /// Computes the digest of `input` in one call.
+    fn digest(input: &[u8]) -> Self::Output {
+        let mut hasher = Self::new();
+        hasher.update(input);
+
+        hasher.finalize()
+    }
+}
+
+/// Object-safe counterpart of [`Digest`].
+///
+/// Implemented for every [`Digest`]; outputs are returned as byte vectors since their size is
+/// only known at runtime.
+pub trait DynDigest {
+    /// Absorbs `input` into the hash state.
+    fn update(&mut self, input: &[u8]);
+
+    /// Returns the digest and resets the hasher to its initial state.
+    fn finalize_reset(&mut self) -> Vec<u8>;
+
+    /// Consumes the boxed hasher and returns the digest.
+    fn finalize_boxed(self: Box<Self>) -> Vec<u8>;
+
+    /// Resets the hasher to its initial state, discarding any absorbed data.
+    fn reset(&mut self);
+
+    /// Output size in bytes.
+    fn output_size(&self) -> usize;
+
+    /// Internal block size in bytes.
+    fn block_size(&self) -> usize;
+
+    /// Clones the hasher, including its absorbed state, behind a new box.
+    fn box_clone(&self) -> Box<dyn DynDigest>;
+}
+
+impl<D: Digest + 'static> DynDigest for D {
+    fn update(&mut self, input: &[u8]) {
+        Digest::update(self, input);
+    }
+
+    fn finalize_reset(&mut self) -> Vec<u8> {
+        Digest::finalize_reset(self).as_ref().to_vec()
+    }
+
+    fn finalize_boxed(self: Box<Self>) -> Vec<u8> {
+        Digest::finalize(*self).as_ref().to_vec()
+    }
+
+    fn reset(&mut self) {
+        *self = D::new();
+    }
+
+    fn output_size(&self) -> usize {
+        D::OUTPUT_SIZE
+    }
+
+    fn block_size(&self) -> usize {
+        D::BLOCK_SIZE
+    }
+
+    fn box_clone(&self) -> Box<dyn DynDigest> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn DynDigest> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
+
+/// Implements [`Digest`] by forwarding to the inherent `new` / `update` / `finalize`.
+///
+/// The inherent digest type is copied into an [`Output`] of `$output_size` bytes, so every
+/// hasher exposes the same output wrapper to generic code.
+macro_rules! impl_digest {
+    ($hasher:ty, $output_size:expr, $block_size:expr) => {
+        impl Digest for $hasher {
+            type Output = Output<$output_size>;
+
+            const OUTPUT_SIZE: usize = $output_size;
+            const BLOCK_SIZE: usize = $block_size;
+
+            fn new() -> Self {
+                <$hasher>::new()
+            }
+
+            fn update(&mut self, input: &[u8]) {
+                <$hasher>::update(self, input);
+            }
+
+            fn finalize(self) -> Self::Output {
+                let mut bytes = [0u8; $output_size];
+                bytes.copy_from_slice(<$hasher>::finalize(self).as_ref());
+
+                Output(bytes)
+            }
+        }
+    };
+}
+
+impl_digest!(Sha224, 28, 64);
+impl_digest!(Sha256, 32, 64);
+impl_digest!(Sha384, 48, 128);
+impl_digest!(Sha512, 64, 128);
+impl_digest!(Sha512_224, 28, 128);
+impl_digest!(Sha512_256, 32, 128);
+impl_digest!(Sha3_224, 28, SHA3_224_RATE);
+impl_digest!(Sha3_256, 32, SHA3_256_RATE);
+impl_digest!(Sha3_384, 48, SHA3_384_RATE);
+impl_digest!(Sha3_512, 64, SHA3_512_RATE);
+impl_digest!(Keccak256, 32, SHA3_256_RATE);
+impl_digest!(Blake2b512, 64, 128);
+impl_digest!(Blake2s256, 32, 64);
+impl_digest!(Blake3, 32, 64);
+impl_digest!(Ripemd160, 20, 64);
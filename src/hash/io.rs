@@ -0,0 +1,154 @@
+/// `std::io` integration for the hashers.
+///
+/// Ce module permet de hacher des fichiers, sockets ou tout autre flux sans copier les données
+/// dans un `Vec` :
+///
+/// - [`Sha256`] implémente [`Write`], et peut donc servir de destination à [`io::copy`]
+/// - [`sha256_reader`] hache un [`Read`] avec un tampon de taille fixe
+/// - [`HashingReader`] et [`HashingWriter`] enveloppent un flux et hachent les octets qui le
+///   traversent, pour n'importe quel [`Digest`]
+///
+/// Les erreurs du flux sous-jacent sont propagées telles quelles en `io::Error`.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::io::{HashingWriter, sha256_reader};
+/// use cryptography::hash::{Sha256, sha256};
+///
+/// let data = b"streamed through a copy";
+/// assert_eq!(sha256_reader(&data[..]).unwrap(), sha256(data));
+///
+/// let mut writer = HashingWriter::<_, Sha256>::new(Vec::new());
+/// std::io::copy(&mut &data[..], &mut writer).unwrap();
+///
+/// let (copy, digest) = writer.finalize();
+/// assert_eq!(copy, data);
+/// assert_eq!(digest.to_u256(), sha256(data));
+/// ```
+use crate::hash::digest::Digest;
+use crate::hash::sha256::hasher::Sha256;
+
+use crate::primitives::U256;
+
+use std::io::{self, ErrorKind, Read, Write};
+
+/// Size of the stack buffer used by [`sha256_reader`].
+pub const READ_BUFFER_LEN: usize = 16 * 1024;
+
+/// Feeds written bytes into the hasher; never fails and never buffers.
+impl Write for Sha256 {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.update(buf);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Computes the SHA-256 hash of everything `reader` yields until end of stream.
+///
+/// # Arguments
+///
+/// * `reader` - Any byte source; read in chunks of [`READ_BUFFER_LEN`] bytes
+///
+/// # Returns
+///
+/// The digest, or the first error returned by `reader` (`Interrupted` reads are retried)
+pub fn sha256_reader<R: Read>(mut reader: R) -> io::Result<U256> {
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; READ_BUFFER_LEN];
+
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => return Ok(hasher.finalize()),
+            Ok(n) => hasher.update(&buffer[..n]),
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Reader adapter that hashes every byte read through it.
+pub struct HashingReader<R, D: Digest = Sha256> {
+    inner: R,
+    hasher: D,
+}
+
+impl<R: Read, D: Digest> HashingReader<R, D> {
+    /// Wraps `inner` with a fresh hasher.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            hasher: D::new(),
+        }
+    }
+
+    /// Returns a reference to the wrapped reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Consumes the adapter and returns the wrapped reader with the digest of the bytes read.
+    pub fn finalize(self) -> (R, D::Output) {
+        (self.inner, self.hasher.finalize())
+    }
+}
+
+impl<R: Read, D: Digest> Read for HashingReader<R, D> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.update(&buf[..n]);
+
+        Ok(n)
+    }
+}
+
+/// Writer adapter that hashes every byte accepted by the wrapped writer.
+pub struct HashingWriter<W, D: Digest = Sha256> {
+    inner: W,
+    hasher: D,
+}
+
+impl<W: Write, D: Digest> HashingWriter<W, D> {
+    /// Wraps `inner` with a fresh hasher.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            hasher: D::new(),
+        }
+    }
+
+    /// Returns a reference to the wrapped writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Consumes the adapter and returns the wrapped writer with the digest of the bytes written.
+    ///
+    /// The writer is not flushed; call [`Write::flush`] first if it buffers.
+    pub fn finalize(self) -> (W, D::Output) {
+        (self.inner, self.hasher.finalize())
+    }
+}
+
+impl<W: Write, D: Digest> Write for HashingWriter<W, D> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Only the bytes the inner writer accepted are part of the stream
+        let n = self.inner.write(buf)?;
+        self.hasher.update(&buf[..n]);
+
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
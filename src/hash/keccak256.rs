@@ -0,0 +1,154 @@
+/// Ethereum-style Keccak-256 and address derivation.
+///
+/// Ce module fournit Keccak-256 avec le padding original de Keccak (`0x01`), tel qu'utilisé par
+/// Ethereum et les chaînes EVM. Il diffère de SHA3-256 uniquement par l'octet de domaine : les
+/// deux fonctions partagent l'éponge de [`sha3`](crate::hash::sha3) mais ne sont pas interchangeables.
+///
+/// Il fournit aussi la dérivation d'une adresse Ethereum (20 derniers octets du Keccak-256 de la
+/// clé publique secp256k1 non compressée) et son rendu avec la somme de contrôle EIP-55.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::keccak256::{keccak256, to_checksum_address};
+///
+/// let hash = keccak256(b"");
+/// assert_eq!(hash.0[0], 0xc5);
+///
+/// let address = [0x5a, 0xae, 0xb6, 0x05, 0x3f, 0x3e, 0x94, 0xc9, 0xb9, 0xa0,
+///                0x9f, 0x33, 0x66, 0x94, 0x35, 0xe7, 0xef, 0x1b, 0xea, 0xed];
+/// assert_eq!(to_checksum_address(&address), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+/// ```
+use crate::hash::sha3::SHA3_256_RATE;
+use crate::hash::sha3::sponge::{DOMAIN_KECCAK, Sponge};
+
+use crate::primitives::U256;
+
+use std::fmt::{Display, Formatter, Result};
+
+/// Errors returned when deriving an address from a malformed public key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AddressError {
+    /// The key is neither 65 bytes (`0x04` ‖ X ‖ Y) nor 64 bytes (X ‖ Y).
+    InvalidLength(usize),
+    /// The 65-byte key does not start with the uncompressed point tag `0x04`.
+    InvalidPrefix(u8),
+}
+
+impl Display for AddressError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            AddressError::InvalidLength(len) => {
+                write!(f, "invalid public key length: {len} bytes")
+            }
+            AddressError::InvalidPrefix(tag) => {
+                write!(f, "invalid uncompressed point prefix: 0x{tag:02x}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AddressError {}
+
+/// Streaming Keccak-256 state (original Keccak padding).
+#[derive(Clone)]
+pub struct Keccak256 {
+    sponge: Sponge,
+}
+
+impl Keccak256 {
+    /// Creates an empty hasher.
+    pub fn new() -> Self {
+        Self {
+            sponge: Sponge::new(SHA3_256_RATE, DOMAIN_KECCAK),
+        }
+    }
+
+    /// Absorbs `input` into the hash state.
+    pub fn update(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    /// Consumes the hasher and returns the Keccak-256 digest of everything absorbed.
+    pub fn finalize(self) -> U256 {
+        let mut out = [0u8; 32];
+        self.sponge.finalize().squeeze(&mut out);
+
+        U256(out)
+    }
+
+    /// Resets the hasher to its initial state, discarding any absorbed data.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for Keccak256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes the Keccak-256 hash of the input data.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// A [`U256`] containing the 256-bit hash output
+pub fn keccak256(input: &[u8]) -> U256 {
+    let mut hasher = Keccak256::new();
+    hasher.update(input);
+
+    hasher.finalize()
+}
+
+/// Derives the 20-byte Ethereum address of an uncompressed secp256k1 public key.
+///
+/// # Arguments
+///
+/// * `public_key` - Either `0x04 ‖ X ‖ Y` (65 bytes) or the raw `X ‖ Y` coordinates (64 bytes)
+///
+/// # Returns
+///
+/// The last 20 bytes of `keccak256(X ‖ Y)`, or an [`AddressError`] for malformed keys
+pub fn eth_address(public_key: &[u8]) -> std::result::Result<[u8; 20], AddressError> {
+    let coordinates = match public_key.len() {
+        65 if public_key[0] == 0x04 => &public_key[1..],
+        65 => return Err(AddressError::InvalidPrefix(public_key[0])),
+        64 => public_key,
+        len => return Err(AddressError::InvalidLength(len)),
+    };
+
+    let hash = keccak256(coordinates);
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash.0[12..]);
+
+    Ok(address)
+}
+
+/// Renders an address as `0x`-prefixed hex with the EIP-55 mixed-case checksum.
+///
+/// Each hex letter is uppercased when the matching nibble of `keccak256(lowercase_hex)` is 8 or more.
+pub fn to_checksum_address(address: &[u8; 20]) -> String {
+    let lower: String = address.iter().map(|b| format!("{:02x}", b)).collect();
+    let hash = keccak256(lower.as_bytes());
+
+    let mut out = String::with_capacity(42);
+    out.push_str("0x");
+
+    for (i, c) in lower.chars().enumerate() {
+        let nibble = (hash.0[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0F;
+
+        if nibble >= 8 {
+            out.push(c.to_ascii_uppercase());
+        } else {
+            out.push(c);
+        }
+    }
+
+    out
+}
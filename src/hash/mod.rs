@@ -1,7 +1,10 @@
 /// Hash algorithms exposed by the crate.
 ///
-/// Ce module expose les fonctions de hachage cryptographiques implémentées en Rust pur.
-/// Actuellement, seul SHA-256 est fourni.
+/// Ce module expose les fonctions de hachage cryptographiques implémentées en Rust pur :
+/// la famille SHA-2 32 bits (SHA-256, SHA-224) et 64 bits (SHA-512, SHA-384, SHA-512/224,
+/// SHA-512/256), ainsi que SHA-3, SHAKE et les fonctions dérivées de SP 800-185, construites
+/// sur l'éponge Keccak, Keccak-256 tel qu'utilisé par Ethereum, BLAKE2, BLAKE3, RIPEMD-160
+/// et les compositions utilisées par Bitcoin.
 ///
 /// # Exemple
 ///
@@ -9,6 +12,34 @@
 /// use cryptography::hash::sha256;
 /// let hash = sha256(b"hello world");
 /// ```
+pub mod bitcoin;
+pub mod blake2;
+pub mod blake3;
+pub mod digest;
+pub mod io;
+pub mod keccak256;
+pub mod output;
+pub mod ripemd160;
+pub mod sha224;
 pub mod sha256;
+pub mod sha3;
+pub mod sha512;
+pub mod sp800_185;
 
-pub use sha256::core::sha256;
+pub use bitcoin::{hash160, sha256d};
+pub use blake2::blake2b::{Blake2b, Blake2b512, blake2b512};
+pub use blake2::blake2s::{Blake2s, Blake2s256, blake2s256};
+pub use blake3::{Blake3, blake3};
+pub use digest::{Digest, DynDigest};
+pub use keccak256::{Keccak256, keccak256};
+pub use output::{Output, Sha256Digest};
+pub use ripemd160::{Ripemd160, ripemd160};
+pub use sha3::core::{sha3_224, sha3_256, sha3_384, sha3_512};
+pub use sha3::hasher::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+pub use sha3::shake::{Shake128, Shake256, XofReader, shake128, shake256};
+pub use sha224::{Sha224, sha224};
+pub use sha256::core::{sha256, sha256_digest};
+pub use sha256::hasher::Sha256;
+pub use sha256::many::sha256_many;
+pub use sha512::core::{sha384, sha512, sha512_224, sha512_256};
+pub use sha512::hasher::{Sha384, Sha512, Sha512_224, Sha512_256};
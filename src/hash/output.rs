@@ -0,0 +1,213 @@
+/// Fixed-size digest output type.
+///
+/// Ce module fournit [`Output`], un tableau d'octets de taille fixe destiné aux sorties de
+/// hachage. Contrairement à [`U256`], il n'a ni opérateurs arithmétiques ni ordre
+/// d'entier : l'égalité est évaluée en temps constant et l'affichage suit le format hexadécimal
+/// minuscule des outils usuels (`sha256sum`, `openssl dgst`).
+///
+/// La conversion vers [`U256`] reste explicite, pour les comparaisons de preuve de travail.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::output::Sha256Digest;
+/// use cryptography::hash::sha256::core::sha256_digest;
+///
+/// let digest = sha256_digest(b"abc");
+/// let shown = digest.to_string();
+///
+/// assert!(shown.starts_with("ba7816bf"));
+/// assert_eq!(shown.parse::<Sha256Digest>(), Ok(digest));
+/// ```
+use crate::primitives::U256;
+use crate::primitives::ct::ct_eq;
+
+use std::fmt::{Debug, Display, Formatter, LowerHex, Result, UpperHex};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// Digest of a SHA-256 computation.
+pub type Sha256Digest = Output<32>;
+
+/// Errors returned when parsing a digest from hexadecimal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HexError {
+    /// The string does not hold exactly `2 * N` hex digits.
+    InvalidLength { expected: usize, found: usize },
+    /// A character at the given byte index is not a hex digit.
+    InvalidCharacter(usize),
+}
+
+impl Display for HexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            HexError::InvalidLength { expected, found } => {
+                write!(
+                    f,
+                    "invalid hex length: expected {expected} digits, found {found}"
+                )
+            }
+            HexError::InvalidCharacter(index) => {
+                write!(f, "invalid hex character at index {index}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for HexError {}
+
+/// Digest of `N` bytes, in the order produced by the hash function.
+#[derive(Copy, Clone)]
+pub struct Output<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> Output<N> {
+    /// Output size in bytes.
+    pub const LEN: usize = N;
+
+    /// Returns the digest bytes.
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Compares two digests in time independent of their contents.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        ct_eq(&self.0, &other.0)
+    }
+
+    /// Parses a digest from `2 * N` hex digits (either case, no prefix).
+    ///
+    /// # Returns
+    ///
+    /// The digest, or a [`HexError`] for a wrong length or a non-hex character
+    pub fn from_hex(hex: &str) -> std::result::Result<Self, HexError> {
+        let bytes = hex.as_bytes();
+
+        if bytes.len() != 2 * N {
+            return Err(HexError::InvalidLength {
+                expected: 2 * N,
+                found: bytes.len(),
+            });
+        }
+
+        let nibble = |i: usize| match bytes[i] {
+            c @ b'0'..=b'9' => Ok(c - b'0'),
+            c @ b'a'..=b'f' => Ok(c - b'a' + 10),
+            c @ b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err(HexError::InvalidCharacter(i)),
+        };
+
+        let mut out = [0u8; N];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = (nibble(2 * i)? << 4) | nibble(2 * i + 1)?;
+        }
+
+        Ok(Output(out))
+    }
+}
+
+impl Output<32> {
+    /// Reinterprets the digest as a big-endian [`U256`], e.g. to compare it with a target.
+    pub fn to_u256(&self) -> U256 {
+        U256(self.0)
+    }
+}
+
+/// Equality is evaluated in constant time.
+impl<const N: usize> PartialEq for Output<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+impl<const N: usize> Eq for Output<N> {}
+
+/// Hashes the bytes, consistently with the byte-wise equality.
+impl<const N: usize> Hash for Output<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<const N: usize> LowerHex for Output<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in self.0.iter() {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const N: usize> UpperHex for Output<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in self.0.iter() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Displays the digest as lowercase hex.
+impl<const N: usize> Display for Output<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        LowerHex::fmt(self, f)
+    }
+}
+
+impl<const N: usize> Debug for Output<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "Output({:x})", self)
+    }
+}
+
+impl<const N: usize> FromStr for Output<N> {
+    type Err = HexError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+/// View `Output` as a byte slice.
+impl<const N: usize> AsRef<[u8]> for Output<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Builds an `Output` from its bytes.
+impl<const N: usize> From<[u8; N]> for Output<N> {
+    fn from(value: [u8; N]) -> Self {
+        Output(value)
+    }
+}
+
+/// Converts an `Output` into its backing array.
+impl<const N: usize> From<Output<N>> for [u8; N] {
+    fn from(value: Output<N>) -> Self {
+        value.0
+    }
+}
+
+/// Reinterprets a 32-byte digest as a big-endian [`U256`].
+impl From<Output<32>> for U256 {
+    fn from(value: Output<32>) -> Self {
+        value.to_u256()
+    }
+}
+
+/// Wraps the bytes of a [`U256`] as a 32-byte digest.
+impl From<U256> for Output<32> {
+    fn from(value: U256) -> Self {
+        Output(value.0)
+    }
+}
@@ -0,0 +1,246 @@
+//! RIPEMD-160 cryptographic hash implementation.
+//!
+//! Ce module fournit RIPEMD-160 (Dobbertin, Bosselaers, Preneel, 1996), une fonction de
+//! hachage Merkle–Damgård à deux lignes parallèles de 80 étapes sur des blocs de 64 octets.
+//! Contrairement à SHA-2, les mots du message et la longueur finale sont en little-endian.
+//!
+//! RIPEMD-160 n'est plus recommandé seul ; il est fourni pour la compatibilité Bitcoin
+//! (voir [`hash160`](crate::hash::bitcoin::hash160)).
+//!
+//! # Exemple
+//!
+//! ```
+//! use cryptography::hash::ripemd160::{Ripemd160, ripemd160};
+//!
+//! let mut hasher = Ripemd160::new();
+//! hasher.update(b"hello world");
+//!
+//! assert_eq!(hasher.finalize(), ripemd160(b"hello world"));
+//! ```
+//!
+//! # Références
+//!
+//! - H. Dobbertin, A. Bosselaers, B. Preneel, *RIPEMD-160: A Strengthened Version of RIPEMD*
+
+/// Initial chaining value.
+pub const H160_INIT: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+/// Additive constants of the left line, one per group of 16 steps.
+const K_LEFT: [u32; 5] = [0x00000000, 0x5A827999, 0x6ED9EBA1, 0x8F1BBCDC, 0xA953FD4E];
+
+/// Additive constants of the right line, one per group of 16 steps.
+const K_RIGHT: [u32; 5] = [0x50A28BE6, 0x5C4DD124, 0x6D703EF3, 0x7A6D76E9, 0x00000000];
+
+/// Message word selection of the left line.
+const R_LEFT: [usize; 80] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, //
+    7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9, 5, 2, 14, 11, 8, //
+    3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12, //
+    1, 9, 11, 10, 0, 8, 12, 4, 13, 3, 7, 15, 14, 5, 6, 2, //
+    4, 0, 5, 9, 7, 12, 2, 10, 14, 1, 3, 8, 11, 6, 15, 13,
+];
+
+/// Message word selection of the right line.
+const R_RIGHT: [usize; 80] = [
+    5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12, //
+    6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8, 12, 4, 9, 1, 2, //
+    15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13, //
+    8, 6, 4, 1, 3, 11, 15, 0, 5, 12, 2, 13, 9, 7, 10, 14, //
+    12, 15, 10, 4, 1, 5, 8, 7, 6, 2, 13, 14, 0, 3, 9, 11,
+];
+
+/// Left rotation amounts of the left line.
+const S_LEFT: [u32; 80] = [
+    11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8, //
+    7, 6, 8, 13, 11, 9, 7, 15, 7, 12, 15, 9, 11, 7, 13, 12, //
+    11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5, //
+    11, 12, 14, 15, 14, 15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12, //
+    9, 15, 5, 11, 6, 8, 13, 12, 5, 12, 13, 14, 11, 8, 5, 6,
+];
+
+/// Left rotation amounts of the right line.
+const S_RIGHT: [u32; 80] = [
+    8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6, //
+    9, 13, 15, 7, 12, 8, 9, 11, 7, 7, 12, 7, 6, 15, 13, 11, //
+    9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5, //
+    15, 5, 8, 11, 14, 14, 6, 14, 6, 9, 12, 9, 12, 5, 15, 8, //
+    8, 5, 12, 9, 12, 5, 14, 6, 8, 13, 6, 5, 15, 13, 11, 11,
+];
+
+/// Boolean function of round `round` (0 to 4).
+#[inline(always)]
+fn f(round: usize, x: u32, y: u32, z: u32) -> u32 {
+    match round {
+        0 => x ^ y ^ z,
+        1 => (x & y) | (!x & z),
+        2 => (x | !y) ^ z,
+        3 => (x & z) | (y & !z),
+        _ => x ^ (y | !z),
+    }
+}
+
+/// Compresses one 64-byte block into the chaining value.
+///
+/// # Arguments
+///
+/// * `block` - A 64-byte message block (sixteen little-endian words)
+/// * `state` - The chaining value, updated in place
+pub fn compress(block: &[u8; 64], state: &mut [u32; 5]) {
+    let mut x = [0u32; 16];
+    for (word, chunk) in x.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+
+    let [mut al, mut bl, mut cl, mut dl, mut el] = *state;
+    let [mut ar, mut br, mut cr, mut dr, mut er] = *state;
+
+    for j in 0..80 {
+        let round = j / 16;
+
+        let t = al
+            .wrapping_add(f(round, bl, cl, dl))
+            .wrapping_add(x[R_LEFT[j]])
+            .wrapping_add(K_LEFT[round])
+            .rotate_left(S_LEFT[j])
+            .wrapping_add(el);
+        al = el;
+        el = dl;
+        dl = cl.rotate_left(10);
+        cl = bl;
+        bl = t;
+
+        // The right line runs the boolean functions in reverse order
+        let t = ar
+            .wrapping_add(f(4 - round, br, cr, dr))
+            .wrapping_add(x[R_RIGHT[j]])
+            .wrapping_add(K_RIGHT[round])
+            .rotate_left(S_RIGHT[j])
+            .wrapping_add(er);
+        ar = er;
+        er = dr;
+        dr = cr.rotate_left(10);
+        cr = br;
+        br = t;
+    }
+
+    let t = state[1].wrapping_add(cl).wrapping_add(dr);
+    state[1] = state[2].wrapping_add(dl).wrapping_add(er);
+    state[2] = state[3].wrapping_add(el).wrapping_add(ar);
+    state[3] = state[4].wrapping_add(al).wrapping_add(br);
+    state[4] = state[0].wrapping_add(bl).wrapping_add(cr);
+    state[0] = t;
+}
+
+/// Streaming RIPEMD-160 state.
+#[derive(Clone)]
+pub struct Ripemd160 {
+    state: [u32; 5],
+    buffer: [u8; 64],
+    buffer_len: usize,
+    length: u64,
+}
+
+impl Ripemd160 {
+    /// Creates a hasher initialized with [`H160_INIT`].
+    pub fn new() -> Self {
+        Self {
+            state: H160_INIT,
+            buffer: [0u8; 64],
+            buffer_len: 0,
+            length: 0,
+        }
+    }
+
+    /// Absorbs `input` into the hash state.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The next chunk of the message (any length, including zero)
+    pub fn update(&mut self, input: &[u8]) {
+        let mut input = input;
+        self.length = self.length.wrapping_add(input.len() as u64);
+
+        if self.buffer_len > 0 {
+            let take = (64 - self.buffer_len).min(input.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&input[..take]);
+            self.buffer_len += take;
+            input = &input[take..];
+
+            if self.buffer_len < 64 {
+                return;
+            }
+
+            compress(&self.buffer, &mut self.state);
+            self.buffer_len = 0;
+        }
+
+        let mut blocks = input.chunks_exact(64);
+
+        for block in &mut blocks {
+            // chunks_exact guarantees 64 bytes
+            let block: &[u8; 64] = block.try_into().unwrap();
+            compress(block, &mut self.state);
+        }
+
+        let rem = blocks.remainder();
+        self.buffer[..rem.len()].copy_from_slice(rem);
+        self.buffer_len = rem.len();
+    }
+
+    /// Consumes the hasher and returns the RIPEMD-160 digest of everything absorbed.
+    ///
+    /// # Returns
+    ///
+    /// The 20-byte digest (chaining value words serialized in little-endian)
+    pub fn finalize(mut self) -> [u8; 20] {
+        let bit_len = self.length << 3;
+        let rem = self.buffer_len;
+
+        let mut block = [0u8; 64];
+        block[..rem].copy_from_slice(&self.buffer[..rem]);
+        block[rem] = 0x80;
+
+        if rem > 55 {
+            compress(&block, &mut self.state);
+            block = [0; 64];
+        }
+
+        // Same padding as MD4/MD5: the bit length is appended in little-endian
+        block[56..64].copy_from_slice(&bit_len.to_le_bytes());
+        compress(&block, &mut self.state);
+
+        let mut out = [0u8; 20];
+        for (chunk, word) in out.chunks_exact_mut(4).zip(self.state.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+
+        out
+    }
+
+    /// Resets the hasher to its initial state, discarding any absorbed data.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for Ripemd160 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes the RIPEMD-160 hash of the input data.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// The 20-byte digest
+pub fn ripemd160(input: &[u8]) -> [u8; 20] {
+    let mut hasher = Ripemd160::new();
+    hasher.update(input);
+
+    hasher.finalize()
+}
@@ -0,0 +1,138 @@
+/// SHA-224 cryptographic hash implementation.
+///
+/// Ce module fournit SHA-224 (FIPS 180-4), qui réutilise entièrement la compression SHA-256
+/// ([`compress`](crate::hash::sha256::core::compress) / [`all_rounds`](crate::hash::sha256::computations::all_rounds)).
+/// Seules la valeur initiale ([`H224_INIT`]) et la taille de sortie (28 octets) changent.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::sha224::{Sha224, sha224};
+///
+/// let mut hasher = Sha224::new();
+/// hasher.update(b"hello world");
+///
+/// assert_eq!(hasher.finalize(), sha224(b"hello world"));
+/// ```
+use crate::hash::sha256::H224_INIT;
+use crate::hash::sha256::hasher::Sha256;
+
+use std::fmt::{Display, Formatter, Result};
+
+/// 224-bit digest produced by SHA-224, stored as 28 big-endian bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct H224(pub [u8; 28]);
+
+impl H224 {
+    /// Truncates a SHA-256 chaining value to its first seven words.
+    fn from_state(state: [u32; 8]) -> Self {
+        let mut out = [0u8; 28];
+
+        for (i, v) in state.iter().take(7).enumerate() {
+            out[i * 4..i * 4 + 4].copy_from_slice(&v.to_be_bytes());
+        }
+
+        H224(out)
+    }
+}
+
+impl Display for H224 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for (i, byte) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(":")?;
+            }
+
+            write!(f, "{:02X}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts an `H224` into its backing 28-byte array.
+impl From<H224> for [u8; 28] {
+    fn from(value: H224) -> Self {
+        value.0
+    }
+}
+
+/// Builds an `H224` from a 28-byte big-endian array.
+impl From<[u8; 28]> for H224 {
+    fn from(value: [u8; 28]) -> Self {
+        H224(value)
+    }
+}
+
+/// View `H224` as a byte slice.
+impl AsRef<[u8]> for H224 {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Streaming SHA-224 state.
+///
+/// Thin wrapper around the SHA-256 hasher seeded with [`H224_INIT`].
+#[derive(Clone)]
+pub struct Sha224 {
+    inner: Sha256,
+}
+
+impl Sha224 {
+    /// Creates a hasher initialized with [`H224_INIT`].
+    pub fn new() -> Self {
+        Self {
+            inner: Sha256::with_state(H224_INIT),
+        }
+    }
+
+    /// Absorbs `input` into the hash state.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The next chunk of the message (any length, including zero)
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    /// Consumes the hasher and returns the SHA-224 digest of everything absorbed.
+    pub fn finalize(self) -> H224 {
+        H224::from_state(self.inner.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state, discarding any absorbed data.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for Sha224 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes the SHA-224 hash of the input data.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// An [`H224`] containing the 224-bit hash output
+///
+/// # Example
+///
+/// ```
+/// use cryptography::hash::sha224::sha224;
+/// let hash = sha224(b"abc");
+/// assert_eq!(hash.0[0], 0x23);
+/// ```
+pub fn sha224(input: &[u8]) -> H224 {
+    let mut hasher = Sha224::new();
+    hasher.update(input);
+
+    hasher.finalize()
+}
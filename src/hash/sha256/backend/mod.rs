@@ -0,0 +1,180 @@
+/// SHA-256 compression backends with runtime dispatch.
+///
+/// Ce module choisit, à l'exécution, l'implémentation de la compression SHA-256 :
+///
+/// - [`Backend::ShaNi`] : instructions x86 SHA-NI (`sha256rnds2`, `sha256msg1/2`), utilisées
+///   lorsque `is_x86_feature_detected!("sha")` est vrai
+/// - [`Backend::Portable`] : la compression scalaire de [`core`](crate::hash::sha256::core),
+///   toujours disponible
+///
+/// Le backend portable peut être imposé, pour comparer les deux chemins sur une même machine,
+/// soit en définissant la variable d'environnement [`FORCE_PORTABLE_ENV`] (lue une seule fois,
+/// au premier appel), soit à l'exécution via [`set_force_portable`].
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::sha256::backend::{Backend, compress_blocks};
+/// use cryptography::hash::sha256::H256_INIT;
+///
+/// let blocks = [[0u8; 64]; 4];
+///
+/// let mut fast = H256_INIT;
+/// compress_blocks(&mut fast, &blocks);
+///
+/// let mut portable = H256_INIT;
+/// Backend::Portable.compress_blocks(&mut portable, &blocks).unwrap();
+///
+/// assert_eq!(fast, portable);
+/// ```
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod shani;
+
+use crate::hash::sha256::core::compress_blocks as compress_blocks_portable;
+
+use std::fmt::{Display, Formatter, Result};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Environment variable that forces the portable backend when set to a non-empty value other
+/// than `0`.
+pub const FORCE_PORTABLE_ENV: &str = "CRYPTOGRAPHY_FORCE_PORTABLE";
+
+/// Runtime override set by [`set_force_portable`].
+static FORCE_PORTABLE: AtomicBool = AtomicBool::new(false);
+
+/// Whether [`FORCE_PORTABLE_ENV`] was set, read once.
+static ENV_FORCED: OnceLock<bool> = OnceLock::new();
+
+/// Backend selected from the CPU features, computed once.
+static DETECTED: OnceLock<Backend> = OnceLock::new();
+
+/// Error returned when a backend is requested on a CPU that cannot run it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BackendError {
+    /// The backend's instructions are not supported by this CPU or target.
+    Unavailable(Backend),
+}
+
+impl Display for BackendError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            BackendError::Unavailable(backend) => {
+                write!(
+                    f,
+                    "SHA-256 backend {backend:?} is not available on this CPU"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for BackendError {}
+
+/// SHA-256 compression implementations.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// Scalar Rust implementation, available on every target.
+    Portable,
+    /// x86 SHA extensions.
+    ShaNi,
+}
+
+impl Backend {
+    /// Returns the fastest backend supported by the CPU, ignoring any override.
+    pub fn detect() -> Backend {
+        if Backend::ShaNi.is_available() {
+            Backend::ShaNi
+        } else {
+            Backend::Portable
+        }
+    }
+
+    /// Returns `true` if this backend can run on the current CPU.
+    pub fn is_available(self) -> bool {
+        match self {
+            Backend::Portable => true,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Backend::ShaNi => shani::is_supported(),
+            #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+            Backend::ShaNi => false,
+        }
+    }
+
+    /// Compresses `blocks` in order into `state` with this backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The chaining value, updated in place
+    /// * `blocks` - Consecutive 64-byte message blocks
+    ///
+    /// # Returns
+    ///
+    /// [`BackendError::Unavailable`] (leaving `state` untouched) if the backend is not
+    /// [available](Backend::is_available) on this CPU
+    pub fn compress_blocks(
+        self,
+        state: &mut [u32; 8],
+        blocks: &[[u8; 64]],
+    ) -> std::result::Result<(), BackendError> {
+        if !self.is_available() {
+            return Err(BackendError::Unavailable(self));
+        }
+
+        // SAFETY: availability was checked just above
+        unsafe { self.compress_blocks_unchecked(state, blocks) };
+
+        Ok(())
+    }
+
+    /// Compresses `blocks` without checking that the backend can run.
+    ///
+    /// # Safety
+    ///
+    /// The backend must be [available](Backend::is_available) on the current CPU.
+    unsafe fn compress_blocks_unchecked(self, state: &mut [u32; 8], blocks: &[[u8; 64]]) {
+        match self {
+            Backend::Portable => compress_blocks_portable(state, blocks),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            // SAFETY: the caller guarantees the SHA-NI features are present
+            Backend::ShaNi => unsafe { shani::compress_blocks(state, blocks) },
+            #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+            Backend::ShaNi => unreachable!("SHA-NI is never available off x86"),
+        }
+    }
+}
+
+/// Forces the portable backend (`true`) or restores automatic selection (`false`).
+///
+/// Takes effect for every subsequent call, from any thread.
+pub fn set_force_portable(force: bool) {
+    FORCE_PORTABLE.store(force, Ordering::Relaxed);
+}
+
+/// Returns `true` if portable code is forced, by [`set_force_portable`] or the environment.
+pub fn portable_forced() -> bool {
+    FORCE_PORTABLE.load(Ordering::Relaxed)
+        || *ENV_FORCED.get_or_init(|| {
+            std::env::var_os(FORCE_PORTABLE_ENV).is_some_and(|v| !v.is_empty() && v != "0")
+        })
+}
+
+/// Returns the backend used by [`compress_blocks`], taking overrides into account.
+pub fn active_backend() -> Backend {
+    if portable_forced() {
+        return Backend::Portable;
+    }
+
+    *DETECTED.get_or_init(Backend::detect)
+}
+
+/// Compresses `blocks` in order into `state` with the [active](active_backend) backend.
+///
+/// # Arguments
+///
+/// * `state` - The chaining value, updated in place
+/// * `blocks` - Consecutive 64-byte message blocks
+pub fn compress_blocks(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
+    // SAFETY: active_backend only returns a detected backend or the portable one
+    unsafe { active_backend().compress_blocks_unchecked(state, blocks) };
+}
@@ -0,0 +1,100 @@
+/// SHA-256 compression with the x86 SHA extensions.
+///
+/// Les instructions SHA-NI travaillent sur l'état réorganisé en deux registres `ABEF` et
+/// `CDGH`. Chaque `sha256rnds2` calcule deux tours ; `sha256msg1` et `sha256msg2` produisent
+/// l'expansion du message quatre mots à la fois.
+///
+/// # Références
+///
+/// - Intel, *Intel SHA Extensions*, 2013
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::hash::sha256::K256;
+
+/// Returns `true` if the CPU supports every feature used by [`compress_blocks`].
+pub fn is_supported() -> bool {
+    is_x86_feature_detected!("sha")
+        && is_x86_feature_detected!("sse2")
+        && is_x86_feature_detected!("ssse3")
+        && is_x86_feature_detected!("sse4.1")
+}
+
+/// Compresses `blocks` in order into `state`.
+///
+/// # Safety
+///
+/// The caller must ensure that [`is_supported`] returns `true`.
+#[target_feature(enable = "sha,sse2,ssse3,sse4.1")]
+pub unsafe fn compress_blocks(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
+    // Byte shuffle turning big-endian message words into little-endian lanes
+    let mask = _mm_set_epi64x(0x0C0D_0E0F_0809_0A0B, 0x0405_0607_0001_0203);
+
+    // SAFETY: `state` holds 8 words, read as two unaligned 128-bit vectors
+    let (dcba, hgfe) = unsafe {
+        (
+            _mm_loadu_si128(state.as_ptr() as *const __m128i),
+            _mm_loadu_si128(state.as_ptr().add(4) as *const __m128i),
+        )
+    };
+
+    let cdab = _mm_shuffle_epi32(dcba, 0xB1);
+    let efgh = _mm_shuffle_epi32(hgfe, 0x1B);
+    let mut abef = _mm_alignr_epi8(cdab, efgh, 8);
+    let mut cdgh = _mm_blend_epi16(efgh, cdab, 0xF0);
+
+    for block in blocks {
+        let abef_save = abef;
+        let cdgh_save = cdgh;
+
+        // SAFETY: each block is 64 bytes, read as four unaligned 128-bit vectors
+        let mut msgs = unsafe {
+            let ptr = block.as_ptr() as *const __m128i;
+            [
+                _mm_shuffle_epi8(_mm_loadu_si128(ptr), mask),
+                _mm_shuffle_epi8(_mm_loadu_si128(ptr.add(1)), mask),
+                _mm_shuffle_epi8(_mm_loadu_si128(ptr.add(2)), mask),
+                _mm_shuffle_epi8(_mm_loadu_si128(ptr.add(3)), mask),
+            ]
+        };
+
+        // Sixteen groups of four rounds; msgs[i % 4] holds W[4i..4i + 4]
+        for i in 0..16 {
+            // SAFETY: K256 holds 64 words, so 4 * i + 3 is in bounds
+            let k = unsafe { _mm_loadu_si128(K256.as_ptr().add(4 * i) as *const __m128i) };
+            let msg = _mm_add_epi32(msgs[i % 4], k);
+
+            cdgh = _mm_sha256rnds2_epu32(cdgh, abef, msg);
+
+            if (3..15).contains(&i) {
+                // W[4(i+1)..] += W[4i - 3..] then finish the schedule with sigma1
+                let carried = _mm_alignr_epi8(msgs[i % 4], msgs[(i + 3) % 4], 4);
+                let next = _mm_add_epi32(msgs[(i + 1) % 4], carried);
+                msgs[(i + 1) % 4] = _mm_sha256msg2_epu32(next, msgs[i % 4]);
+            }
+
+            abef = _mm_sha256rnds2_epu32(abef, cdgh, _mm_shuffle_epi32(msg, 0x0E));
+
+            if (1..13).contains(&i) {
+                // Start the sigma0 part of the schedule three groups ahead
+                msgs[(i + 3) % 4] = _mm_sha256msg1_epu32(msgs[(i + 3) % 4], msgs[i % 4]);
+            }
+        }
+
+        abef = _mm_add_epi32(abef, abef_save);
+        cdgh = _mm_add_epi32(cdgh, cdgh_save);
+    }
+
+    let feba = _mm_shuffle_epi32(abef, 0x1B);
+    let dchg = _mm_shuffle_epi32(cdgh, 0xB1);
+    let dcba = _mm_blend_epi16(feba, dchg, 0xF0);
+    let hgfe = _mm_alignr_epi8(dchg, feba, 8);
+
+    // SAFETY: `state` holds 8 words, written as two unaligned 128-bit vectors
+    unsafe {
+        _mm_storeu_si128(state.as_mut_ptr() as *mut __m128i, dcba);
+        _mm_storeu_si128(state.as_mut_ptr().add(4) as *mut __m128i, hgfe);
+    }
+}
@@ -3,7 +3,7 @@
 /// SHA-256 computation functions and round operations.
 ///
 /// Ce module fournit les helpers bas-niveau pour la compression SHA-256 : opérations bitwise,
-/// boucle principale 64 tours, etc.
+/// 64 tours entièrement déroulés, etc.
 ///
 /// # Opérations bitwise
 ///
@@ -157,9 +157,235 @@ pub fn maj(a: u32, b: u32, c: u32) -> u32 {
     (a & b) ^ (a & c) ^ (b & c)
 }
 
+/// One SHA-256 round with the working variables named in their current positions.
+///
+/// Instead of shifting the eight variables after every round, the caller rotates the names:
+/// only `d` (which becomes the next `e`) and `h` (which becomes the next `a`) are written.
+macro_rules! round {
+    ($a:ident, $b:ident, $c:ident, $d:ident, $e:ident, $f:ident, $g:ident, $h:ident, $wi:expr, $ki:expr) => {{
+        // h + K[i] + W[i] does not depend on the previous round and can start early
+        let t1 = $h
+            .wrapping_add($ki)
+            .wrapping_add($wi)
+            .wrapping_add(big_sigma1($e))
+            .wrapping_add(ch($e, $f, $g));
+        let t2 = big_sigma0($a).wrapping_add(maj($a, $b, $c));
+
+        $d = $d.wrapping_add(t1);
+        $h = t1.wrapping_add(t2);
+    }};
+}
+
+/// Reads W[j] for the first sixteen rounds.
+macro_rules! load {
+    ($w:ident, $j:expr) => {
+        $w[$j]
+    };
+}
+
+/// Expands W[j + 16k] in place: W[i] = σ₁(W[i-2]) + W[i-7] + σ₀(W[i-15]) + W[i-16].
+macro_rules! expand {
+    ($w:ident, $j:expr) => {{
+        $w[$j] = $w[$j]
+            .wrapping_add(small_sigma0($w[($j + 1) & 15]))
+            .wrapping_add($w[($j + 9) & 15])
+            .wrapping_add(small_sigma1($w[($j + 14) & 15]));
+        $w[$j]
+    }};
+}
+
+/// Sixteen rounds starting at round `$base`, taking message words from `$word`.
+macro_rules! sixteen_rounds {
+    ($w:ident, $base:expr, $word:ident, $a:ident, $b:ident, $c:ident, $d:ident, $e:ident, $f:ident, $g:ident, $h:ident) => {
+        round!($a, $b, $c, $d, $e, $f, $g, $h, $word!($w, 0), K256[$base]);
+        round!(
+            $h,
+            $a,
+            $b,
+            $c,
+            $d,
+            $e,
+            $f,
+            $g,
+            $word!($w, 1),
+            K256[$base + 1]
+        );
+        round!(
+            $g,
+            $h,
+            $a,
+            $b,
+            $c,
+            $d,
+            $e,
+            $f,
+            $word!($w, 2),
+            K256[$base + 2]
+        );
+        round!(
+            $f,
+            $g,
+            $h,
+            $a,
+            $b,
+            $c,
+            $d,
+            $e,
+            $word!($w, 3),
+            K256[$base + 3]
+        );
+        round!(
+            $e,
+            $f,
+            $g,
+            $h,
+            $a,
+            $b,
+            $c,
+            $d,
+            $word!($w, 4),
+            K256[$base + 4]
+        );
+        round!(
+            $d,
+            $e,
+            $f,
+            $g,
+            $h,
+            $a,
+            $b,
+            $c,
+            $word!($w, 5),
+            K256[$base + 5]
+        );
+        round!(
+            $c,
+            $d,
+            $e,
+            $f,
+            $g,
+            $h,
+            $a,
+            $b,
+            $word!($w, 6),
+            K256[$base + 6]
+        );
+        round!(
+            $b,
+            $c,
+            $d,
+            $e,
+            $f,
+            $g,
+            $h,
+            $a,
+            $word!($w, 7),
+            K256[$base + 7]
+        );
+        round!(
+            $a,
+            $b,
+            $c,
+            $d,
+            $e,
+            $f,
+            $g,
+            $h,
+            $word!($w, 8),
+            K256[$base + 8]
+        );
+        round!(
+            $h,
+            $a,
+            $b,
+            $c,
+            $d,
+            $e,
+            $f,
+            $g,
+            $word!($w, 9),
+            K256[$base + 9]
+        );
+        round!(
+            $g,
+            $h,
+            $a,
+            $b,
+            $c,
+            $d,
+            $e,
+            $f,
+            $word!($w, 10),
+            K256[$base + 10]
+        );
+        round!(
+            $f,
+            $g,
+            $h,
+            $a,
+            $b,
+            $c,
+            $d,
+            $e,
+            $word!($w, 11),
+            K256[$base + 11]
+        );
+        round!(
+            $e,
+            $f,
+            $g,
+            $h,
+            $a,
+            $b,
+            $c,
+            $d,
+            $word!($w, 12),
+            K256[$base + 12]
+        );
+        round!(
+            $d,
+            $e,
+            $f,
+            $g,
+            $h,
+            $a,
+            $b,
+            $c,
+            $word!($w, 13),
+            K256[$base + 13]
+        );
+        round!(
+            $c,
+            $d,
+            $e,
+            $f,
+            $g,
+            $h,
+            $a,
+            $b,
+            $word!($w, 14),
+            K256[$base + 14]
+        );
+        round!(
+            $b,
+            $c,
+            $d,
+            $e,
+            $f,
+            $g,
+            $h,
+            $a,
+            $word!($w, 15),
+            K256[$base + 15]
+        );
+    };
+}
+
 /// Executes all 64 rounds of the SHA-256 compression function.
 ///
-/// Standard SHA-256 implementation that computes the 64 rounds through a loop.
+/// The rounds are fully unrolled and branch-free: the first sixteen read the message words
+/// directly, the next 48 expand the schedule in the 16-word circular buffer as they go.
+/// Sixteen rounds rotate the variable names back to their starting positions.
 ///
 /// # Algorithm
 ///
@@ -175,57 +401,14 @@ pub fn maj(a: u32, b: u32, c: u32) -> u32 {
 ///
 /// * `state` - Current hash state [a, b, c, d, e, f, g, h], updated in-place
 /// * `w` - Message schedule array (16 values, circular buffer)
+#[inline(always)]
 pub fn all_rounds(state: &mut [u32; 8], mut w: [u32; 16]) {
-    // Load hash state into working variables
-    let mut a = state[0];
-    let mut b = state[1];
-    let mut c = state[2];
-    let mut d = state[3];
-    let mut e = state[4];
-    let mut f = state[5];
-    let mut g = state[6];
-    let mut h = state[7];
-
-    for i in 0..64 {
-        if i >= 16 {
-            // Circular buffer indexing: access W[i-2], W[i-7], W[i-15], W[i-16] via modulo 16
-            let w16 = w[(i - 16) & 15];
-            let w15 = w[(i - 15) & 15];
-            let w7 = w[(i - 7) & 15];
-            let w2 = w[(i - 2) & 15];
-
-            let s0 = small_sigma0(w15);
-            let s1 = small_sigma1(w2);
-
-            w[i & 15] = w16.wrapping_add(s0).wrapping_add(w7).wrapping_add(s1);
-        }
-
-        let wi = w[i & 15];
-        let ki = K256[i];
-
-        let bs1 = big_sigma1(e);
-        let ch = ch(e, f, g);
-
-        let bs0 = big_sigma0(a);
-        let maj = maj(a, b, c);
-
-        let t1 = h
-            .wrapping_add(bs1)
-            .wrapping_add(ch)
-            .wrapping_add(wi)
-            .wrapping_add(ki);
-
-        let t2 = bs0.wrapping_add(maj);
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
 
-        h = g;
-        g = f;
-        f = e;
-        e = d.wrapping_add(t1);
-        d = c;
-        c = b;
-        b = a;
-        a = t1.wrapping_add(t2);
-    }
+    sixteen_rounds!(w, 0, load, a, b, c, d, e, f, g, h);
+    sixteen_rounds!(w, 16, expand, a, b, c, d, e, f, g, h);
+    sixteen_rounds!(w, 32, expand, a, b, c, d, e, f, g, h);
+    sixteen_rounds!(w, 48, expand, a, b, c, d, e, f, g, h);
 
     state[0] = state[0].wrapping_add(a);
     state[1] = state[1].wrapping_add(b);
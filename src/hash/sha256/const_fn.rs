@@ -0,0 +1,163 @@
+/// Compile-time SHA-256.
+///
+/// Ce module fournit [`sha256_const`], une version `const fn` de SHA-256 permettant de
+/// calculer des hachages (tags de protocole, séparateurs de domaine) directement dans des
+/// items `const` ou `static`, sans coût au démarrage.
+///
+/// [`compress_const`] reproduit [`compress`](crate::hash::sha256::core::compress) et
+/// [`all_rounds`](crate::hash::sha256::computations::all_rounds) avec uniquement des
+/// constructions autorisées en contexte `const` (boucles `while`, pas d'itérateurs). Cette
+/// version n'est pas optimisée : à l'exécution, utiliser [`sha256`](crate::hash::sha256()).
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::sha256;
+/// use cryptography::hash::sha256::const_fn::sha256_const;
+/// use cryptography::primitives::U256;
+///
+/// const TAG: U256 = sha256_const(b"nebula/node-id");
+///
+/// assert_eq!(TAG, sha256(b"nebula/node-id"));
+/// ```
+use crate::hash::sha256::{H256_INIT, K256};
+
+use crate::primitives::U256;
+
+/// Compresses one 64-byte block, in a form usable in `const` contexts.
+///
+/// # Arguments
+///
+/// * `block` - A 64-byte block to process
+/// * `state` - The current hash state
+///
+/// # Returns
+///
+/// The updated hash state
+pub const fn compress_const(block: &[u8; 64], state: [u32; 8]) -> [u32; 8] {
+    let mut w = [0u32; 64];
+
+    let mut i = 0;
+    while i < 16 {
+        w[i] = u32::from_be_bytes([
+            block[4 * i],
+            block[4 * i + 1],
+            block[4 * i + 2],
+            block[4 * i + 3],
+        ]);
+        i += 1;
+    }
+
+    while i < 64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+        i += 1;
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+
+    i = 0;
+    while i < 64 {
+        let bs1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let t1 = h
+            .wrapping_add(bs1)
+            .wrapping_add(ch)
+            .wrapping_add(K256[i])
+            .wrapping_add(w[i]);
+
+        let bs0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = bs0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+        i += 1;
+    }
+
+    [
+        state[0].wrapping_add(a),
+        state[1].wrapping_add(b),
+        state[2].wrapping_add(c),
+        state[3].wrapping_add(d),
+        state[4].wrapping_add(e),
+        state[5].wrapping_add(f),
+        state[6].wrapping_add(g),
+        state[7].wrapping_add(h),
+    ]
+}
+
+/// Computes the SHA-256 hash of the input data at compile time.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// A [`U256`] equal to `sha256(input)`
+pub const fn sha256_const(input: &[u8]) -> U256 {
+    let mut state = H256_INIT;
+    let len = input.len();
+
+    let mut offset = 0;
+    while offset + 64 <= len {
+        let mut block = [0u8; 64];
+        let mut j = 0;
+        while j < 64 {
+            block[j] = input[offset + j];
+            j += 1;
+        }
+
+        state = compress_const(&block, state);
+        offset += 64;
+    }
+
+    // Final block(s): remaining bytes, the 0x80 marker, then the bit length
+    let mut block = [0u8; 64];
+    let rem = len - offset;
+
+    let mut j = 0;
+    while j < rem {
+        block[j] = input[offset + j];
+        j += 1;
+    }
+    block[rem] = 0x80;
+
+    if rem > 55 {
+        state = compress_const(&block, state);
+        block = [0u8; 64];
+    }
+
+    let bit_len = ((len as u64) << 3).to_be_bytes();
+    j = 0;
+    while j < 8 {
+        block[56 + j] = bit_len[j];
+        j += 1;
+    }
+
+    state = compress_const(&block, state);
+
+    let mut out = [0u8; 32];
+    let mut k = 0;
+    while k < 8 {
+        let bytes = state[k].to_be_bytes();
+        out[4 * k] = bytes[0];
+        out[4 * k + 1] = bytes[1];
+        out[4 * k + 2] = bytes[2];
+        out[4 * k + 3] = bytes[3];
+        k += 1;
+    }
+
+    U256(out)
+}
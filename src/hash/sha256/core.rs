@@ -19,8 +19,12 @@
 /// # Fonctions
 ///
 /// - [`compress`] : compresse un bloc 512 bits
+/// - [`compress_blocks`] : compresse une suite de blocs consécutifs
 /// - [`sha256`] : hash une entrée de taille arbitraire
+/// - [`sha256_digest`] : idem, avec le type de sortie dédié [`Sha256Digest`]
+use crate::hash::output::Sha256Digest;
 use crate::hash::sha256::H256_INIT;
+use crate::hash::sha256::backend;
 use crate::hash::sha256::computations::all_rounds;
 
 use crate::primitives::U256;
@@ -43,15 +47,41 @@ use crate::primitives::U256;
 /// 3. Updates the hash state with the computed values
 #[inline(always)]
 pub fn compress(block: &[u8; 64], state: &mut [u32; 8]) {
+    all_rounds(state, load_words(block));
+}
+
+/// Reads the 16 big-endian message words of a block.
+#[inline(always)]
+fn load_words(block: &[u8; 64]) -> [u32; 16] {
     let mut w = [0u32; 16];
 
-    for (i, slot) in w.iter_mut().enumerate().take(16) {
+    for (slot, word) in w.iter_mut().zip(block.as_chunks::<4>().0) {
         // Read u32 in big-endian format
-        let idx = i * 4;
-        *slot = u32::from_be_bytes([block[idx], block[idx + 1], block[idx + 2], block[idx + 3]]);
+        *slot = u32::from_be_bytes(*word);
     }
 
-    all_rounds(state, w);
+    w
+}
+
+/// Compresses consecutive 64-byte blocks into the state with the portable implementation.
+///
+/// Equivalent to calling [`compress`] on each block in order, but the state stays in locals
+/// for the whole run and is written back once at the end.
+/// [`backend::compress_blocks`](crate::hash::sha256::backend::compress_blocks) has the same
+/// signature and picks a hardware implementation when one is available.
+///
+/// # Arguments
+///
+/// * `state` - The current hash state (8 x 32-bit values), updated in-place
+/// * `blocks` - The blocks to process, in message order
+pub fn compress_blocks(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
+    let mut s = *state;
+
+    for block in blocks {
+        all_rounds(&mut s, load_words(block));
+    }
+
+    *state = s;
 }
 
 /// Computes the SHA-256 hash of the input data.
@@ -65,11 +95,12 @@ pub fn compress(block: &[u8; 64], state: &mut [u32; 8]) {
 ///
 /// # Returns
 ///
-/// A [`U256`] containing the 256-bit hash output
+/// A [`U256`] containing the 256-bit hash output. Kept for compatibility; new code should prefer
+/// [`sha256_digest`], whose output type has no integer semantics.
 ///
 /// # Algorithm Steps
 ///
-/// 1. **Block Processing**: Iterates through complete 64-byte blocks, compressing each
+/// 1. **Block Processing**: Compresses all complete 64-byte blocks with the active backend
 /// 2. **Padding**: Handles the final partial block with proper SHA-256 padding:
 ///    - Appends a single '1' bit (0x80 byte)
 ///    - Pads with zeros to align to 56 bytes (mod 64)
@@ -86,36 +117,49 @@ pub fn compress(block: &[u8; 64], state: &mut [u32; 8]) {
 pub fn sha256(input: &[u8]) -> U256 {
     let mut state = H256_INIT;
 
-    let mut i = 0;
-    let len = input.len();
-
-    while i + 64 <= len {
-        // Convert slice to 64-byte block
-        let block: &[u8; 64] = input[i..i + 64].try_into().unwrap();
-        compress(block, &mut state);
-        i += 64;
-    }
+    // Whole blocks go through the fastest available backend
+    let (blocks, rest) = input.as_chunks::<64>();
+    backend::compress_blocks(&mut state, blocks);
 
-    let mut block = [0u8; 64];
-    let rem = len - i;
+    let mut tail = [[0u8; 64]; 2];
+    let rem = rest.len();
 
     // Copy remaining bytes and add padding bit
-    block[..rem].copy_from_slice(&input[i..]);
-    block[rem] = 0x80; // SHA-256 padding bit
+    tail[0][..rem].copy_from_slice(rest);
+    tail[0][rem] = 0x80; // SHA-256 padding bit
 
-    if rem > 55 {
-        // Need extra block for message length
-        compress(&block, &mut state);
-        block = [0; 64];
-    }
+    // Need extra block for message length
+    let count = if rem > 55 { 2 } else { 1 };
 
-    let bit_len = (len as u64) << 3; // Convert bytes to bits
+    let bit_len = (input.len() as u64) << 3; // Convert bytes to bits
     let len_bytes = bit_len.to_be_bytes();
 
     // Insert message length in the last 8 bytes
-    block[56..64].copy_from_slice(&len_bytes);
+    tail[count - 1][56..64].copy_from_slice(&len_bytes);
 
-    compress(&block, &mut state);
+    // The padding blocks go through the backend too, so short messages also use it
+    backend::compress_blocks(&mut state, &tail[..count]);
 
     U256::from(state)
 }
+
+/// Computes the SHA-256 hash of the input data as a [`Sha256Digest`].
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// The 32-byte digest, displayed as lowercase hex and compared in constant time
+///
+/// # Example
+///
+/// ```
+/// use cryptography::hash::sha256::core::sha256_digest;
+/// let digest = sha256_digest(b"abc");
+/// assert_eq!(digest.to_string()[..8], *"ba7816bf");
+/// ```
+pub fn sha256_digest(input: &[u8]) -> Sha256Digest {
+    Sha256Digest::from(sha256(input))
+}
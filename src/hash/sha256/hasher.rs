@@ -0,0 +1,145 @@
+/// Incremental SHA-256 hasher.
+///
+/// Ce module fournit [`Sha256`], un état SHA-256 qui peut être alimenté en plusieurs fois
+/// via [`Sha256::update`] puis finalisé avec [`Sha256::finalize`]. Le résultat est identique
+/// à celui de [`sha256`](crate::hash::sha256::core::sha256) quel que soit le découpage de l'entrée.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::{Sha256, sha256};
+///
+/// let mut hasher = Sha256::new();
+/// hasher.update(b"hello ");
+/// hasher.update(b"world");
+///
+/// assert_eq!(hasher.finalize(), sha256(b"hello world"));
+/// ```
+use crate::hash::output::Sha256Digest;
+use crate::hash::sha256::H256_INIT;
+use crate::hash::sha256::backend::compress_blocks;
+
+use crate::primitives::U256;
+
+use std::slice;
+
+/// Streaming SHA-256 state.
+///
+/// Holds the chaining value, a 64-byte buffer for the pending partial block and the total
+/// number of bytes absorbed so far. Cloning a hasher forks the computation: both copies can
+/// be updated and finalized independently.
+#[derive(Clone)]
+pub struct Sha256 {
+    state: [u32; 8],
+    buffer: [u8; 64],
+    buffer_len: usize,
+    length: u64,
+}
+
+impl Sha256 {
+    /// Creates a hasher initialized with [`H256_INIT`].
+    pub fn new() -> Self {
+        Self::with_state(H256_INIT)
+    }
+
+    /// Creates a hasher starting from an arbitrary chaining value.
+    ///
+    /// Used by the SHA-2 variants that share the SHA-256 compression but not its IV.
+    pub(crate) fn with_state(state: [u32; 8]) -> Self {
+        Self::with_state_and_length(state, 0)
+    }
+
+    /// Creates a hasher resuming after `length` bytes, with no buffered data.
+    ///
+    /// `length` must be a multiple of 64 for the padding to be correct.
+    pub(crate) fn with_state_and_length(state: [u32; 8], length: u64) -> Self {
+        Self {
+            state,
+            buffer: [0u8; 64],
+            buffer_len: 0,
+            length,
+        }
+    }
+
+    /// Returns the chaining value, the number of buffered bytes and the total length.
+    pub(crate) fn raw_parts(&self) -> ([u32; 8], usize, u64) {
+        (self.state, self.buffer_len, self.length)
+    }
+
+    /// Absorbs `input` into the hash state.
+    ///
+    /// Can be called any number of times; complete 64-byte blocks are compressed
+    /// immediately and the trailing bytes are kept for the next call.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The next chunk of the message (any length, including zero)
+    pub fn update(&mut self, input: &[u8]) {
+        let mut input = input;
+        self.length = self.length.wrapping_add(input.len() as u64);
+
+        if self.buffer_len > 0 {
+            // Top up the pending block first
+            let take = (64 - self.buffer_len).min(input.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&input[..take]);
+            self.buffer_len += take;
+            input = &input[take..];
+
+            if self.buffer_len < 64 {
+                return;
+            }
+
+            compress_blocks(&mut self.state, slice::from_ref(&self.buffer));
+            self.buffer_len = 0;
+        }
+
+        let (blocks, rem) = input.as_chunks::<64>();
+        compress_blocks(&mut self.state, blocks);
+
+        self.buffer[..rem.len()].copy_from_slice(rem);
+        self.buffer_len = rem.len();
+    }
+
+    /// Applies the final padding and returns the raw chaining value.
+    pub(crate) fn finalize_state(mut self) -> [u32; 8] {
+        let bit_len = self.length << 3; // Convert bytes to bits
+        let rem = self.buffer_len;
+
+        let mut tail = [[0u8; 64]; 2];
+        tail[0][..rem].copy_from_slice(&self.buffer[..rem]);
+        tail[0][rem] = 0x80; // SHA-256 padding bit
+
+        // Need extra block for message length
+        let count = if rem > 55 { 2 } else { 1 };
+
+        tail[count - 1][56..64].copy_from_slice(&bit_len.to_be_bytes());
+        compress_blocks(&mut self.state, &tail[..count]);
+
+        self.state
+    }
+
+    /// Consumes the hasher and returns the SHA-256 digest of everything absorbed.
+    ///
+    /// # Returns
+    ///
+    /// A [`U256`] containing the 256-bit hash output
+    pub fn finalize(self) -> U256 {
+        U256::from(self.finalize_state())
+    }
+
+    /// Consumes the hasher and returns the digest as a [`Sha256Digest`].
+    pub fn finalize_digest(self) -> Sha256Digest {
+        Sha256Digest::from(self.finalize())
+    }
+
+    /// Resets the hasher to its initial state, discarding any absorbed data.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for Sha256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,457 @@
+/// Multi-buffer SHA-256 for batches of independent messages.
+///
+/// Ce module fournit [`sha256_many`], qui hache plusieurs messages indépendants en parallèle
+/// dans les voies SIMD : 4 messages par registre SSE2, 8 par registre AVX2. L'état de chaque
+/// message occupe une voie ; les mots `a..h` et le message schedule sont transposés.
+///
+/// Sur un CPU doté de SHA-NI, la compression matérielle d'un seul message est plus rapide que
+/// 8 messages en AVX2 : [`MultiBuffer::detect`] choisit alors [`MultiBuffer::Scalar`], qui passe
+/// par [`backend`](crate::hash::sha256::backend).
+///
+/// Les messages sont triés par nombre de blocs avant d'être groupés, afin que les voies d'un
+/// même groupe terminent à peu près en même temps. Le résultat est renvoyé dans l'ordre
+/// d'origine et correspond message par message à [`sha256`].
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::sha256;
+/// use cryptography::hash::sha256::many::sha256_many;
+///
+/// let inputs: [&[u8]; 3] = [b"abc", b"", b"hello world"];
+/// let hashes = sha256_many(&inputs);
+///
+/// assert_eq!(hashes[0], sha256(b"abc"));
+/// assert_eq!(hashes[2], sha256(b"hello world"));
+/// ```
+use crate::hash::sha256::backend::{Backend, active_backend, portable_forced};
+use crate::hash::sha256::core::sha256;
+use crate::hash::sha256::{H256_INIT, K256};
+
+use crate::primitives::U256;
+
+/// Multi-buffer implementations.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MultiBuffer {
+    /// One message at a time with [`sha256`], through the active compression backend.
+    Scalar,
+    /// Four messages per 128-bit SSE2 register.
+    Sse2,
+    /// Eight messages per 256-bit AVX2 register.
+    Avx2,
+}
+
+impl MultiBuffer {
+    /// Returns the fastest implementation for the current CPU.
+    ///
+    /// Picks [`MultiBuffer::Scalar`] when the SHA-NI [backend](active_backend) is active, since
+    /// hardware rounds on one message beat software rounds on eight, and when portable code is
+    /// [forced](crate::hash::sha256::backend::portable_forced). Otherwise returns the widest
+    /// SIMD implementation supported.
+    pub fn detect() -> MultiBuffer {
+        if portable_forced() || active_backend() == Backend::ShaNi {
+            return MultiBuffer::Scalar;
+        }
+
+        if MultiBuffer::Avx2.is_available() {
+            MultiBuffer::Avx2
+        } else if MultiBuffer::Sse2.is_available() {
+            MultiBuffer::Sse2
+        } else {
+            MultiBuffer::Scalar
+        }
+    }
+
+    /// Returns `true` if this implementation can run on the current CPU.
+    pub fn is_available(self) -> bool {
+        match self {
+            MultiBuffer::Scalar => true,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            MultiBuffer::Sse2 => is_x86_feature_detected!("sse2"),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            MultiBuffer::Avx2 => is_x86_feature_detected!("avx2"),
+            #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+            _ => false,
+        }
+    }
+
+    /// Number of messages hashed side by side.
+    pub fn lanes(self) -> usize {
+        match self {
+            MultiBuffer::Scalar => 1,
+            MultiBuffer::Sse2 => 4,
+            MultiBuffer::Avx2 => 8,
+        }
+    }
+
+    /// Hashes every message of `inputs` with this implementation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the implementation is not [available](MultiBuffer::is_available) on this CPU.
+    pub fn sha256_many(self, inputs: &[&[u8]]) -> Vec<U256> {
+        assert!(self.is_available(), "{self:?} is not supported by this CPU");
+
+        if self == MultiBuffer::Scalar {
+            return inputs.iter().map(|input| sha256(input)).collect();
+        }
+
+        // Group messages of similar padded length so lanes finish together
+        let mut order: Vec<usize> = (0..inputs.len()).collect();
+        order.sort_by_key(|&i| padded_blocks(inputs[i].len()));
+
+        let mut out = vec![U256::ZERO; inputs.len()];
+        let mut batch: Vec<&[u8]> = Vec::with_capacity(self.lanes());
+
+        for group in order.chunks(self.lanes()) {
+            batch.clear();
+            batch.extend(group.iter().map(|&i| inputs[i]));
+
+            let states = self.hash_batch(&batch);
+            for (&i, state) in group.iter().zip(states) {
+                out[i] = U256::from(state);
+            }
+        }
+
+        out
+    }
+
+    /// Hashes at most [`lanes`](MultiBuffer::lanes) messages and returns their final states.
+    fn hash_batch(self, batch: &[&[u8]]) -> Vec<[u32; 8]> {
+        match self {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            // SAFETY: callers check availability before dispatching
+            MultiBuffer::Sse2 => unsafe { x86::hash_batch_sse2(batch) },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            // SAFETY: callers check availability before dispatching
+            MultiBuffer::Avx2 => unsafe { x86::hash_batch_avx2(batch) },
+            _ => unreachable!("scalar batches are hashed one message at a time"),
+        }
+    }
+}
+
+/// Hashes every message of `inputs` with the widest available SIMD implementation.
+///
+/// # Arguments
+///
+/// * `inputs` - Independent messages of any length
+///
+/// # Returns
+///
+/// One [`U256`] per input, in the same order, equal to `sha256(input)`
+pub fn sha256_many(inputs: &[&[u8]]) -> Vec<U256> {
+    MultiBuffer::detect().sha256_many(inputs)
+}
+
+/// Number of 64-byte blocks of a message once padded (0x80 byte and 64-bit length included).
+fn padded_blocks(len: usize) -> usize {
+    (len + 9).div_ceil(64)
+}
+
+/// Returns block `index` of the padded message.
+#[inline(always)]
+fn padded_block(input: &[u8], index: usize, total: usize) -> [u8; 64] {
+    let mut block = [0u8; 64];
+    let start = index * 64;
+
+    if start + 64 <= input.len() {
+        block.copy_from_slice(&input[start..start + 64]);
+        return block;
+    }
+
+    if start <= input.len() {
+        let rem = input.len() - start;
+        block[..rem].copy_from_slice(&input[start..]);
+        block[rem] = 0x80;
+    }
+
+    if index + 1 == total {
+        let bit_len = (input.len() as u64) << 3;
+        block[56..].copy_from_slice(&bit_len.to_be_bytes());
+    }
+
+    block
+}
+
+/// A vector of 32-bit lanes, one lane per message.
+///
+/// # Safety
+///
+/// Every method may use the instruction set of the implementing type: callers must ensure the
+/// CPU supports it (e.g. by running inside a matching `#[target_feature]` function).
+trait Lanes: Copy {
+    /// Number of 32-bit lanes.
+    const LANES: usize;
+
+    unsafe fn splat(value: u32) -> Self;
+    unsafe fn from_lanes(values: &[u32; 8]) -> Self;
+    unsafe fn to_lanes(self, out: &mut [u32; 8]);
+    unsafe fn add(self, rhs: Self) -> Self;
+    unsafe fn xor(self, rhs: Self) -> Self;
+    unsafe fn and(self, rhs: Self) -> Self;
+    /// Computes `!self & rhs`.
+    unsafe fn andnot(self, rhs: Self) -> Self;
+    unsafe fn or(self, rhs: Self) -> Self;
+    unsafe fn shr(self, n: u32) -> Self;
+    unsafe fn shl(self, n: u32) -> Self;
+
+    #[inline(always)]
+    unsafe fn rotr(self, n: u32) -> Self {
+        // SAFETY: forwarded from the caller
+        unsafe { self.shr(n).or(self.shl(32 - n)) }
+    }
+}
+
+/// Compresses one block per lane into the transposed state.
+///
+/// # Safety
+///
+/// The CPU must support the instruction set of `V` (see [`Lanes`]).
+#[inline(always)]
+unsafe fn compress_lanes<V: Lanes>(state: &mut [V; 8], blocks: &[[u8; 64]]) {
+    // SAFETY: every lane operation below is covered by the caller's guarantee
+    unsafe {
+        let mut w = [V::splat(0); 16];
+        for (t, slot) in w.iter_mut().enumerate() {
+            let mut words = [0u32; 8];
+            for (word, block) in words.iter_mut().zip(blocks) {
+                *word = u32::from_be_bytes([
+                    block[4 * t],
+                    block[4 * t + 1],
+                    block[4 * t + 2],
+                    block[4 * t + 3],
+                ]);
+            }
+            *slot = V::from_lanes(&words);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+        for (t, &k) in K256.iter().enumerate() {
+            if t >= 16 {
+                let w15 = w[(t + 1) & 15];
+                let w2 = w[(t + 14) & 15];
+                let s0 = w15.rotr(7).xor(w15.rotr(18)).xor(w15.shr(3));
+                let s1 = w2.rotr(17).xor(w2.rotr(19)).xor(w2.shr(10));
+                w[t & 15] = w[t & 15].add(s0).add(w[(t + 9) & 15]).add(s1);
+            }
+
+            let big_s1 = e.rotr(6).xor(e.rotr(11)).xor(e.rotr(25));
+            let ch = e.and(f).xor(e.andnot(g));
+            let t1 = h.add(big_s1).add(ch).add(V::splat(k)).add(w[t & 15]);
+
+            let big_s0 = a.rotr(2).xor(a.rotr(13)).xor(a.rotr(22));
+            let maj = a.and(b).xor(a.and(c)).xor(b.and(c));
+            let t2 = big_s0.add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.add(t2);
+        }
+
+        for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *s = s.add(v);
+        }
+    }
+}
+
+/// Hashes up to `V::LANES` messages side by side.
+///
+/// # Safety
+///
+/// The CPU must support the instruction set of `V` (see [`Lanes`]).
+#[inline(always)]
+unsafe fn hash_batch<V: Lanes>(batch: &[&[u8]]) -> Vec<[u32; 8]> {
+    let totals: Vec<usize> = batch
+        .iter()
+        .map(|input| padded_blocks(input.len()))
+        .collect();
+    let max_blocks = totals.iter().copied().max().unwrap_or(0);
+
+    // SAFETY (all lane operations below): covered by the caller's guarantee
+    let mut state = H256_INIT.map(|x| unsafe { V::splat(x) });
+    let mut out = vec![[0u32; 8]; batch.len()];
+    let mut blocks = [[0u8; 64]; 8];
+
+    for index in 0..max_blocks {
+        for (lane, block) in blocks.iter_mut().take(V::LANES).enumerate() {
+            // Finished and unused lanes compress zeros; their result was already saved
+            *block = match batch.get(lane) {
+                Some(input) if index < totals[lane] => padded_block(input, index, totals[lane]),
+                _ => [0u8; 64],
+            };
+        }
+
+        unsafe { compress_lanes(&mut state, &blocks[..V::LANES]) };
+
+        let mut words = [[0u32; 8]; 8];
+        for (slot, v) in words.iter_mut().zip(state.iter()) {
+            unsafe { v.to_lanes(slot) };
+        }
+
+        for (lane, total) in totals.iter().enumerate() {
+            if index + 1 == *total {
+                for (i, word) in out[lane].iter_mut().enumerate() {
+                    *word = words[i][lane];
+                }
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    use super::{Lanes, hash_batch};
+
+    /// Four lanes in an SSE2 register.
+    #[derive(Copy, Clone)]
+    struct Sse2(__m128i);
+
+    // SAFETY (all methods): callers guarantee SSE2 per the `Lanes` contract
+    impl Lanes for Sse2 {
+        const LANES: usize = 4;
+
+        #[inline(always)]
+        unsafe fn splat(value: u32) -> Self {
+            Sse2(unsafe { _mm_set1_epi32(value as i32) })
+        }
+
+        #[inline(always)]
+        unsafe fn from_lanes(values: &[u32; 8]) -> Self {
+            Sse2(unsafe { _mm_loadu_si128(values.as_ptr() as *const __m128i) })
+        }
+
+        #[inline(always)]
+        unsafe fn to_lanes(self, out: &mut [u32; 8]) {
+            unsafe { _mm_storeu_si128(out.as_mut_ptr() as *mut __m128i, self.0) }
+        }
+
+        #[inline(always)]
+        unsafe fn add(self, rhs: Self) -> Self {
+            Sse2(unsafe { _mm_add_epi32(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        unsafe fn xor(self, rhs: Self) -> Self {
+            Sse2(unsafe { _mm_xor_si128(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        unsafe fn and(self, rhs: Self) -> Self {
+            Sse2(unsafe { _mm_and_si128(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        unsafe fn andnot(self, rhs: Self) -> Self {
+            Sse2(unsafe { _mm_andnot_si128(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        unsafe fn or(self, rhs: Self) -> Self {
+            Sse2(unsafe { _mm_or_si128(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        unsafe fn shr(self, n: u32) -> Self {
+            Sse2(unsafe { _mm_srl_epi32(self.0, _mm_cvtsi32_si128(n as i32)) })
+        }
+
+        #[inline(always)]
+        unsafe fn shl(self, n: u32) -> Self {
+            Sse2(unsafe { _mm_sll_epi32(self.0, _mm_cvtsi32_si128(n as i32)) })
+        }
+    }
+
+    /// Eight lanes in an AVX2 register.
+    #[derive(Copy, Clone)]
+    struct Avx2(__m256i);
+
+    // SAFETY (all methods): callers guarantee AVX2 per the `Lanes` contract
+    impl Lanes for Avx2 {
+        const LANES: usize = 8;
+
+        #[inline(always)]
+        unsafe fn splat(value: u32) -> Self {
+            Avx2(unsafe { _mm256_set1_epi32(value as i32) })
+        }
+
+        #[inline(always)]
+        unsafe fn from_lanes(values: &[u32; 8]) -> Self {
+            Avx2(unsafe { _mm256_loadu_si256(values.as_ptr() as *const __m256i) })
+        }
+
+        #[inline(always)]
+        unsafe fn to_lanes(self, out: &mut [u32; 8]) {
+            unsafe { _mm256_storeu_si256(out.as_mut_ptr() as *mut __m256i, self.0) }
+        }
+
+        #[inline(always)]
+        unsafe fn add(self, rhs: Self) -> Self {
+            Avx2(unsafe { _mm256_add_epi32(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        unsafe fn xor(self, rhs: Self) -> Self {
+            Avx2(unsafe { _mm256_xor_si256(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        unsafe fn and(self, rhs: Self) -> Self {
+            Avx2(unsafe { _mm256_and_si256(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        unsafe fn andnot(self, rhs: Self) -> Self {
+            Avx2(unsafe { _mm256_andnot_si256(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        unsafe fn or(self, rhs: Self) -> Self {
+            Avx2(unsafe { _mm256_or_si256(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        unsafe fn shr(self, n: u32) -> Self {
+            Avx2(unsafe { _mm256_srl_epi32(self.0, _mm_cvtsi32_si128(n as i32)) })
+        }
+
+        #[inline(always)]
+        unsafe fn shl(self, n: u32) -> Self {
+            Avx2(unsafe { _mm256_sll_epi32(self.0, _mm_cvtsi32_si128(n as i32)) })
+        }
+    }
+
+    /// Hashes up to four messages.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support SSE2.
+    #[target_feature(enable = "sse2")]
+    pub unsafe fn hash_batch_sse2(batch: &[&[u8]]) -> Vec<[u32; 8]> {
+        // SAFETY: SSE2 is enabled for this function and guaranteed by the caller
+        unsafe { hash_batch::<Sse2>(batch) }
+    }
+
+    /// Hashes up to eight messages.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn hash_batch_avx2(batch: &[&[u8]]) -> Vec<[u32; 8]> {
+        // SAFETY: AVX2 is enabled for this function and guaranteed by the caller
+        unsafe { hash_batch::<Avx2>(batch) }
+    }
+}
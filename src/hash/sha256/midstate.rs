@@ -0,0 +1,166 @@
+/// SHA-256 midstates for precomputed prefixes.
+///
+/// Ce module fournit [`Midstate`] : l'état de chaînage SHA-256 après un nombre entier de blocs,
+/// accompagné du nombre d'octets déjà absorbés. Lorsque de nombreux messages partagent les
+/// mêmes premiers blocs, le préfixe est compressé une seule fois puis chaque message repart
+/// du midstate via [`Sha256::from_midstate`].
+///
+/// # Format sérialisé
+///
+/// [`Midstate::to_bytes`] produit 40 octets, stables d'une version à l'autre :
+///
+/// - octets 0 à 31 : les 8 mots de l'état, chacun en big-endian, dans l'ordre `a..h`
+/// - octets 32 à 39 : le nombre d'octets absorbés, en `u64` big-endian (multiple de 64)
+///
+/// Les 32 premiers octets coïncident donc avec la sortie qu'aurait la compression si le
+/// message s'arrêtait là, comme pour les midstates des mineurs Bitcoin.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::sha256::midstate::Midstate;
+/// use cryptography::hash::{Sha256, sha256};
+///
+/// let prefix = [0x42u8; 64];
+/// let midstate = Midstate::from_prefix(&prefix).unwrap();
+///
+/// let mut hasher = Sha256::from_midstate(&midstate);
+/// hasher.update(b"suffix");
+///
+/// assert_eq!(hasher.finalize(), sha256(&[&prefix[..], b"suffix"].concat()));
+/// ```
+use crate::hash::sha256::H256_INIT;
+use crate::hash::sha256::backend::compress_blocks;
+use crate::hash::sha256::hasher::Sha256;
+
+use std::fmt::{Display, Formatter, Result};
+
+/// Size of a serialized midstate in bytes.
+pub const MIDSTATE_LEN: usize = 40;
+
+/// Errors returned when building a midstate.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MidstateError {
+    /// The hasher holds this many buffered bytes that are not yet compressed.
+    PartialBlock(usize),
+    /// The byte count is not a multiple of the 64-byte block size.
+    UnalignedLength(u64),
+}
+
+impl Display for MidstateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            MidstateError::PartialBlock(len) => {
+                write!(f, "{len} buffered bytes do not form a whole block")
+            }
+            MidstateError::UnalignedLength(len) => {
+                write!(f, "byte count {len} is not a multiple of 64")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MidstateError {}
+
+/// SHA-256 chaining value after a whole number of blocks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Midstate {
+    state: [u32; 8],
+    length: u64,
+}
+
+impl Midstate {
+    /// Builds a midstate from a chaining value and the number of bytes it covers.
+    ///
+    /// # Returns
+    ///
+    /// The midstate, or [`MidstateError::UnalignedLength`] if `length` is not a multiple of 64
+    pub fn new(state: [u32; 8], length: u64) -> std::result::Result<Self, MidstateError> {
+        if !length.is_multiple_of(64) {
+            return Err(MidstateError::UnalignedLength(length));
+        }
+
+        Ok(Self { state, length })
+    }
+
+    /// Compresses `prefix` from [`H256_INIT`].
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - Shared message prefix, whose length must be a multiple of 64
+    pub fn from_prefix(prefix: &[u8]) -> std::result::Result<Self, MidstateError> {
+        if !prefix.len().is_multiple_of(64) {
+            return Err(MidstateError::UnalignedLength(prefix.len() as u64));
+        }
+
+        let mut state = H256_INIT;
+        compress_blocks(&mut state, prefix.as_chunks::<64>().0);
+
+        Ok(Self {
+            state,
+            length: prefix.len() as u64,
+        })
+    }
+
+    /// Returns the chaining value.
+    pub fn state(&self) -> [u32; 8] {
+        self.state
+    }
+
+    /// Returns the number of bytes absorbed before this midstate.
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// Serializes the midstate in the stable 40-byte format described in the module docs.
+    pub fn to_bytes(&self) -> [u8; MIDSTATE_LEN] {
+        let mut out = [0u8; MIDSTATE_LEN];
+
+        for (chunk, word) in out[..32].chunks_exact_mut(4).zip(self.state.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        out[32..].copy_from_slice(&self.length.to_be_bytes());
+
+        out
+    }
+
+    /// Parses a midstate serialized by [`Midstate::to_bytes`].
+    ///
+    /// # Returns
+    ///
+    /// The midstate, or [`MidstateError::UnalignedLength`] if the byte count is invalid
+    pub fn from_bytes(bytes: &[u8; MIDSTATE_LEN]) -> std::result::Result<Self, MidstateError> {
+        let mut state = [0u32; 8];
+        for (word, chunk) in state.iter_mut().zip(bytes[..32].chunks_exact(4)) {
+            *word = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+
+        let mut length = [0u8; 8];
+        length.copy_from_slice(&bytes[32..]);
+
+        Self::new(state, u64::from_be_bytes(length))
+    }
+}
+
+impl Sha256 {
+    /// Exports the current state as a [`Midstate`].
+    ///
+    /// # Returns
+    ///
+    /// The midstate, or [`MidstateError::PartialBlock`] if the absorbed length is not a whole
+    /// number of blocks
+    pub fn midstate(&self) -> std::result::Result<Midstate, MidstateError> {
+        let (state, buffer_len, length) = self.raw_parts();
+
+        if buffer_len != 0 {
+            return Err(MidstateError::PartialBlock(buffer_len));
+        }
+
+        Ok(Midstate { state, length })
+    }
+
+    /// Creates a hasher that resumes from `midstate`.
+    pub fn from_midstate(midstate: &Midstate) -> Self {
+        Self::with_state_and_length(midstate.state, midstate.length)
+    }
+}
@@ -16,12 +16,20 @@
 /// # Constantes
 ///
 /// - [`H256_INIT`] : valeurs initiales
+/// - [`H224_INIT`] : valeurs initiales de SHA-224
 /// - [`K256`] : constantes de tour
 ///
 /// # Modules
 ///
+/// - [`backend`] : sélection à l'exécution de la compression (SHA-NI ou portable)
+/// - [`const_fn`] : SHA-256 évaluable à la compilation
 /// - [`core`] : logique de compression
 /// - [`computations`] : helpers bitwise
+/// - [`hasher`] : état incrémental [`Sha256`](hasher::Sha256)
+/// - [`many`] : hachage multi-buffer SIMD de messages indépendants
+/// - [`midstate`] : export et import de l'état après un préfixe de blocs entiers
+/// - [`tagged`] : hachages étiquetés à la BIP-340, préfixe mis en cache
+/// - [`tree`] : mode arborescent versionné, haché en parallèle
 ///
 /// # Exemple
 ///
@@ -29,8 +37,15 @@
 /// use cryptography::hash::sha256;
 /// let hash = sha256(b"hello world");
 /// ```
+pub mod backend;
 pub mod computations;
+pub mod const_fn;
 pub mod core;
+pub mod hasher;
+pub mod many;
+pub mod midstate;
+pub mod tagged;
+pub mod tree;
 
 /// Initial hash values for SHA-256.
 ///
@@ -40,6 +55,14 @@ pub const H256_INIT: [u32; 8] = [
     0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19,
 ];
 
+/// Initial hash values for SHA-224.
+///
+/// Seconds 32 bits des racines carrées fractionnaires des nombres premiers 9 à 16 (FIPS 180-4).
+/// SHA-224 partage la compression de SHA-256 et ne diffère que par ces valeurs et la troncature.
+pub const H224_INIT: [u32; 8] = [
+    0xC1059ED8, 0x367CD507, 0x3070DD17, 0xF70E5939, 0xFFC00B31, 0x68581511, 0x64F98FA7, 0xBEFA4FA4,
+];
+
 /// Round constants for SHA-256.
 ///
 /// 64 constantes, racines cubiques fractionnaires des 64 premiers nombres premiers.
@@ -0,0 +1,229 @@
+/// Parallel tree hashing over SHA-256.
+///
+/// Ce module définit un mode arborescent versionné au-dessus de SHA-256, pour hacher de très
+/// gros volumes sur plusieurs cœurs. Le résultat ne dépend que de l'entrée et de la taille de
+/// chunk, jamais du nombre de threads. Il est **différent** de `sha256(input)`.
+///
+/// # Spécification (version 1)
+///
+/// 1. L'entrée est découpée en chunks de `chunk_len` octets (le dernier peut être plus court ;
+///    une entrée vide donne un unique chunk vide).
+/// 2. Chaque chunk donne une feuille : `SHA256(0x00 || chunk)`.
+/// 3. Les nœuds sont combinés deux à deux, niveau par niveau : `SHA256(0x01 || gauche || droite)`.
+///    Un nœud resté seul en fin de niveau remonte tel quel au niveau suivant.
+/// 4. La racine finale lie la version et les paramètres :
+///    `SHA256(0x02 || version || chunk_len (u64 BE) || longueur de l'entrée (u64 BE) || nœud)`.
+///
+/// Les préfixes `0x00` / `0x01` / `0x02` séparent les domaines : une feuille ne peut pas être
+/// confondue avec un nœud parent, ni un nœud interne avec la racine.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::sha256::tree::{TreeHasher, sha256_tree};
+///
+/// let data = vec![0u8; 10_000];
+///
+/// let serial = TreeHasher::new().chunk_len(1024).unwrap().threads(1).hash(&data);
+/// let parallel = TreeHasher::new().chunk_len(1024).unwrap().threads(4).hash(&data);
+///
+/// assert_eq!(serial, parallel);
+/// assert_ne!(sha256_tree(&data), serial); // the chunk size is part of the output
+/// ```
+use crate::hash::sha256::hasher::Sha256;
+
+use crate::primitives::U256;
+
+use std::fmt::{Display, Formatter, Result};
+use std::thread;
+
+/// Version of the tree mode, bound into every root.
+pub const TREE_VERSION: u8 = 1;
+
+/// Default chunk size: 1 MiB.
+pub const DEFAULT_CHUNK_LEN: usize = 1 << 20;
+
+/// Smallest accepted chunk size.
+pub const MIN_CHUNK_LEN: usize = 1024;
+
+/// Domain prefix of leaf nodes.
+pub const LEAF_PREFIX: u8 = 0x00;
+
+/// Domain prefix of parent nodes.
+pub const PARENT_PREFIX: u8 = 0x01;
+
+/// Domain prefix of the final root.
+pub const ROOT_PREFIX: u8 = 0x02;
+
+/// Error returned for an invalid tree configuration.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TreeError {
+    /// The chunk size is not a power of two or is below [`MIN_CHUNK_LEN`].
+    InvalidChunkLen(usize),
+}
+
+impl Display for TreeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            TreeError::InvalidChunkLen(len) => write!(
+                f,
+                "invalid tree chunk length: {len} (expected a power of two >= {MIN_CHUNK_LEN})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TreeError {}
+
+/// Configuration of the tree mode.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TreeHasher {
+    chunk_len: usize,
+    threads: usize,
+}
+
+impl TreeHasher {
+    /// Creates a configuration with [`DEFAULT_CHUNK_LEN`] and one thread per available core.
+    pub fn new() -> Self {
+        Self {
+            chunk_len: DEFAULT_CHUNK_LEN,
+            threads: 0,
+        }
+    }
+
+    /// Sets the chunk size. It is part of the output: both sides must agree on it.
+    ///
+    /// # Returns
+    ///
+    /// The updated configuration, or [`TreeError::InvalidChunkLen`] if `chunk_len` is not a
+    /// power of two or is below [`MIN_CHUNK_LEN`]
+    pub fn chunk_len(mut self, chunk_len: usize) -> std::result::Result<Self, TreeError> {
+        if !chunk_len.is_power_of_two() || chunk_len < MIN_CHUNK_LEN {
+            return Err(TreeError::InvalidChunkLen(chunk_len));
+        }
+
+        self.chunk_len = chunk_len;
+        Ok(self)
+    }
+
+    /// Sets the number of worker threads; `0` uses one per available core.
+    ///
+    /// Only affects speed, never the output.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Computes the tree hash of `input`.
+    ///
+    /// # Returns
+    ///
+    /// The versioned root described in the module documentation
+    pub fn hash(&self, input: &[u8]) -> U256 {
+        let leaves = self.hash_leaves(input);
+        let node = combine(leaves);
+
+        let mut hasher = Sha256::new();
+        hasher.update(&[ROOT_PREFIX, TREE_VERSION]);
+        hasher.update(&(self.chunk_len as u64).to_be_bytes());
+        hasher.update(&(input.len() as u64).to_be_bytes());
+        hasher.update(&node.0);
+
+        hasher.finalize()
+    }
+
+    /// Hashes every chunk, spreading contiguous runs of chunks across the worker threads.
+    fn hash_leaves(&self, input: &[u8]) -> Vec<U256> {
+        if input.is_empty() {
+            return vec![leaf_hash(&[])];
+        }
+
+        let count = input.len().div_ceil(self.chunk_len);
+        let threads = match self.threads {
+            0 => thread::available_parallelism().map_or(1, |n| n.get()),
+            n => n,
+        }
+        .min(count);
+
+        let mut leaves = vec![U256::ZERO; count];
+
+        if threads <= 1 {
+            for (leaf, chunk) in leaves.iter_mut().zip(input.chunks(self.chunk_len)) {
+                *leaf = leaf_hash(chunk);
+            }
+
+            return leaves;
+        }
+
+        let per_thread = count.div_ceil(threads);
+
+        thread::scope(|scope| {
+            for (outputs, inputs) in leaves
+                .chunks_mut(per_thread)
+                .zip(input.chunks(per_thread * self.chunk_len))
+            {
+                scope.spawn(move || {
+                    for (leaf, chunk) in outputs.iter_mut().zip(inputs.chunks(self.chunk_len)) {
+                        *leaf = leaf_hash(chunk);
+                    }
+                });
+            }
+        });
+
+        leaves
+    }
+}
+
+impl Default for TreeHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes `SHA256(0x00 || chunk)`.
+fn leaf_hash(chunk: &[u8]) -> U256 {
+    let mut hasher = Sha256::new();
+    hasher.update(&[LEAF_PREFIX]);
+    hasher.update(chunk);
+
+    hasher.finalize()
+}
+
+/// Computes `SHA256(0x01 || left || right)`.
+fn parent_hash(left: &U256, right: &U256) -> U256 {
+    let mut hasher = Sha256::new();
+    hasher.update(&[PARENT_PREFIX]);
+    hasher.update(&left.0);
+    hasher.update(&right.0);
+
+    hasher.finalize()
+}
+
+/// Reduces the leaves level by level down to a single node.
+fn combine(mut level: Vec<U256>) -> U256 {
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => parent_hash(left, right),
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+
+    level[0]
+}
+
+/// Computes the tree hash of `input` with the default parameters.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// The version 1 tree root with [`DEFAULT_CHUNK_LEN`] chunks
+pub fn sha256_tree(input: &[u8]) -> U256 {
+    TreeHasher::new().hash(input)
+}
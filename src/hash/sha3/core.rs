@@ -0,0 +1,81 @@
+/// One-shot SHA-3 hashing functions.
+///
+/// Ce module expose une fonction par variante de SHA-3 ; chacune absorbe l'entrée complète
+/// dans l'état incrémental correspondant de [`hasher`](crate::hash::sha3::hasher).
+use crate::hash::sha3::hasher::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+use crate::hash::sha224::H224;
+use crate::hash::sha512::{H384, H512};
+
+use crate::primitives::U256;
+
+/// Computes the SHA3-224 hash of the input data.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// An [`H224`] containing the 224-bit hash output
+pub fn sha3_224(input: &[u8]) -> H224 {
+    let mut hasher = Sha3_224::new();
+    hasher.update(input);
+
+    hasher.finalize()
+}
+
+/// Computes the SHA3-256 hash of the input data.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// A [`U256`] containing the 256-bit hash output
+///
+/// # Example
+///
+/// ```
+/// use cryptography::hash::sha3_256;
+/// let hash = sha3_256(b"abc");
+/// assert_eq!(hash.0[0], 0x3a);
+/// ```
+pub fn sha3_256(input: &[u8]) -> U256 {
+    let mut hasher = Sha3_256::new();
+    hasher.update(input);
+
+    hasher.finalize()
+}
+
+/// Computes the SHA3-384 hash of the input data.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// An [`H384`] containing the 384-bit hash output
+pub fn sha3_384(input: &[u8]) -> H384 {
+    let mut hasher = Sha3_384::new();
+    hasher.update(input);
+
+    hasher.finalize()
+}
+
+/// Computes the SHA3-512 hash of the input data.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// An [`H512`] containing the 512-bit hash output
+pub fn sha3_512(input: &[u8]) -> H512 {
+    let mut hasher = Sha3_512::new();
+    hasher.update(input);
+
+    hasher.finalize()
+}
@@ -0,0 +1,166 @@
+/// Incremental SHA-3 hashers.
+///
+/// Ce module fournit [`Sha3_224`], [`Sha3_256`], [`Sha3_384`] et [`Sha3_512`], chacun étant une
+/// [`Sponge`] configurée avec le rate de la variante et l'octet de domaine SHA-3.
+use crate::hash::sha3::sponge::{DOMAIN_SHA3, Sponge};
+use crate::hash::sha3::{SHA3_224_RATE, SHA3_256_RATE, SHA3_384_RATE, SHA3_512_RATE};
+use crate::hash::sha224::H224;
+use crate::hash::sha512::{H384, H512};
+
+use crate::primitives::U256;
+
+/// Streaming SHA3-224 state.
+#[derive(Clone)]
+pub struct Sha3_224 {
+    sponge: Sponge,
+}
+
+impl Sha3_224 {
+    /// Creates an empty hasher.
+    pub fn new() -> Self {
+        Self {
+            sponge: Sponge::new(SHA3_224_RATE, DOMAIN_SHA3),
+        }
+    }
+
+    /// Absorbs `input` into the hash state.
+    pub fn update(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    /// Consumes the hasher and returns the SHA3-224 digest of everything absorbed.
+    pub fn finalize(self) -> H224 {
+        let mut out = [0u8; 28];
+        self.sponge.finalize().squeeze(&mut out);
+
+        H224(out)
+    }
+
+    /// Resets the hasher to its initial state, discarding any absorbed data.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for Sha3_224 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Streaming SHA3-256 state.
+#[derive(Clone)]
+pub struct Sha3_256 {
+    sponge: Sponge,
+}
+
+impl Sha3_256 {
+    /// Creates an empty hasher.
+    pub fn new() -> Self {
+        Self {
+            sponge: Sponge::new(SHA3_256_RATE, DOMAIN_SHA3),
+        }
+    }
+
+    /// Absorbs `input` into the hash state.
+    pub fn update(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    /// Consumes the hasher and returns the SHA3-256 digest of everything absorbed.
+    pub fn finalize(self) -> U256 {
+        let mut out = [0u8; 32];
+        self.sponge.finalize().squeeze(&mut out);
+
+        U256(out)
+    }
+
+    /// Resets the hasher to its initial state, discarding any absorbed data.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for Sha3_256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Streaming SHA3-384 state.
+#[derive(Clone)]
+pub struct Sha3_384 {
+    sponge: Sponge,
+}
+
+impl Sha3_384 {
+    /// Creates an empty hasher.
+    pub fn new() -> Self {
+        Self {
+            sponge: Sponge::new(SHA3_384_RATE, DOMAIN_SHA3),
+        }
+    }
+
+    /// Absorbs `input` into the hash state.
+    pub fn update(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    /// Consumes the hasher and returns the SHA3-384 digest of everything absorbed.
+    pub fn finalize(self) -> H384 {
+        let mut out = [0u8; 48];
+        self.sponge.finalize().squeeze(&mut out);
+
+        H384(out)
+    }
+
+    /// Resets the hasher to its initial state, discarding any absorbed data.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for Sha3_384 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Streaming SHA3-512 state.
+#[derive(Clone)]
+pub struct Sha3_512 {
+    sponge: Sponge,
+}
+
+impl Sha3_512 {
+    /// Creates an empty hasher.
+    pub fn new() -> Self {
+        Self {
+            sponge: Sponge::new(SHA3_512_RATE, DOMAIN_SHA3),
+        }
+    }
+
+    /// Absorbs `input` into the hash state.
+    pub fn update(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    /// Consumes the hasher and returns the SHA3-512 digest of everything absorbed.
+    pub fn finalize(self) -> H512 {
+        let mut out = [0u8; 64];
+        self.sponge.finalize().squeeze(&mut out);
+
+        H512(out)
+    }
+
+    /// Resets the hasher to its initial state, discarding any absorbed data.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for Sha3_512 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,107 @@
+/// Keccak-f[1600] permutation.
+///
+/// Ce module implémente la permutation Keccak-f[1600] (FIPS 202 §3) sur un état de 25 mots
+/// de 64 bits. Elle est utilisée par l'éponge ([`Sponge`](crate::hash::sha3::sponge::Sponge))
+/// de SHA-3, SHAKE et de toutes les constructions dérivées.
+///
+/// # Étapes d'un tour
+///
+/// | Étape   | Rôle                                                   |
+/// |---------|--------------------------------------------------------|
+/// | `θ`     | XOR de chaque lane avec la parité de deux colonnes      |
+/// | `ρ`     | Rotation de chaque lane d'un décalage fixe             |
+/// | `π`     | Permutation des positions des lanes                    |
+/// | `χ`     | Étape non linéaire sur chaque ligne                    |
+/// | `ι`     | XOR de la constante de tour dans la lane (0, 0)        |
+///
+/// # Références
+///
+/// - FIPS 202: SHA-3 Standard: Permutation-Based Hash and Extendable-Output Functions
+pub const KECCAK_ROUNDS: usize = 24;
+
+/// Round constants for the `ι` step.
+pub const RC: [u64; KECCAK_ROUNDS] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Rotation offsets for the `ρ` step, in the order lanes are visited by `π`.
+const RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// Lane indices visited by the combined `ρ`/`π` walk starting from lane 1.
+const PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// Applies the 24-round Keccak-f[1600] permutation to `state` in-place.
+///
+/// # Arguments
+///
+/// * `state` - The 25 lanes of the state, indexed as `x + 5 * y`
+pub fn keccak_f1600(state: &mut [u64; 25]) {
+    for rc in RC {
+        // θ: XOR each lane with the parity of the two neighbouring columns
+        let mut c = [0u64; 5];
+        for (x, col) in c.iter_mut().enumerate() {
+            *col = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+
+            for y in (0..25).step_by(5) {
+                state[y + x] ^= d;
+            }
+        }
+
+        // ρ and π: rotate each lane and move it to its new position
+        let mut last = state[1];
+        for (&j, &r) in PI.iter().zip(RHO.iter()) {
+            let tmp = state[j];
+            state[j] = last.rotate_left(r);
+            last = tmp;
+        }
+
+        // χ: non-linear mixing along each row
+        for y in (0..25).step_by(5) {
+            let row = [
+                state[y],
+                state[y + 1],
+                state[y + 2],
+                state[y + 3],
+                state[y + 4],
+            ];
+
+            for x in 0..5 {
+                state[y + x] = row[x] ^ ((!row[(x + 1) % 5]) & row[(x + 2) % 5]);
+            }
+        }
+
+        // ι: break the symmetry between rounds
+        state[0] ^= rc;
+    }
+}
@@ -0,0 +1,46 @@
+/// SHA-3 cryptographic hash implementation.
+///
+/// Ce module fournit SHA3-224, SHA3-256, SHA3-384 et SHA3-512 (FIPS 202), ainsi que les XOF
+/// SHAKE128 et SHAKE256. Contrairement à
+/// SHA-2, SHA-3 repose sur une éponge construite au-dessus de la permutation Keccak-f[1600],
+/// ce qui offre une alternative de conception différente en cas de faiblesse de SHA-2.
+///
+/// # Constantes
+///
+/// - [`SHA3_224_RATE`], [`SHA3_256_RATE`], [`SHA3_384_RATE`], [`SHA3_512_RATE`] : rate de l'éponge (octets)
+///
+/// # Modules
+///
+/// - [`keccak`] : permutation Keccak-f[1600]
+/// - [`sponge`] : éponge générique (rate/capacité, padding de domaine)
+/// - [`core`] : fonctions one-shot
+/// - [`hasher`] : états incrémentaux
+/// - [`shake`] : fonctions à sortie extensible SHAKE128 / SHAKE256
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::{Sha3_256, sha3_256};
+///
+/// let mut hasher = Sha3_256::new();
+/// hasher.update(b"hello world");
+///
+/// assert_eq!(hasher.finalize(), sha3_256(b"hello world"));
+/// ```
+pub mod core;
+pub mod hasher;
+pub mod keccak;
+pub mod shake;
+pub mod sponge;
+
+/// Sponge rate of SHA3-224 in bytes (capacity 448 bits).
+pub const SHA3_224_RATE: usize = 144;
+
+/// Sponge rate of SHA3-256 in bytes (capacity 512 bits).
+pub const SHA3_256_RATE: usize = 136;
+
+/// Sponge rate of SHA3-384 in bytes (capacity 768 bits).
+pub const SHA3_384_RATE: usize = 104;
+
+/// Sponge rate of SHA3-512 in bytes (capacity 1024 bits).
+pub const SHA3_512_RATE: usize = 72;
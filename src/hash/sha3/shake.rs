@@ -0,0 +1,156 @@
+/// SHAKE128 and SHAKE256 extendable-output functions.
+///
+/// Ce module fournit les XOF de FIPS 202 au-dessus de l'éponge Keccak déjà utilisée par SHA-3.
+/// Les données sont absorbées via [`Shake128::absorb`] / [`Shake256::absorb`], puis
+/// `finalize` renvoie un [`XofReader`] qui produit un flux de sortie de longueur arbitraire.
+/// Plusieurs petits appels à [`XofReader::squeeze`] donnent exactement les mêmes octets qu'un
+/// seul grand appel, y compris à cheval sur les frontières de rate.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::sha3::shake::{Shake128, shake128};
+///
+/// let mut xof = Shake128::new();
+/// xof.absorb(b"seed");
+///
+/// let mut reader = xof.finalize();
+/// let mut first = [0u8; 10];
+/// let mut second = [0u8; 22];
+/// reader.squeeze(&mut first);
+/// reader.squeeze(&mut second);
+///
+/// let all = shake128(b"seed", 32);
+/// assert_eq!(&all[..10], &first);
+/// assert_eq!(&all[10..], &second);
+/// ```
+use crate::hash::sha3::sponge::{DOMAIN_SHAKE, Sponge, SpongeReader};
+
+/// Sponge rate of SHAKE128 in bytes (capacity 256 bits).
+pub const SHAKE128_RATE: usize = 168;
+
+/// Sponge rate of SHAKE256 in bytes (capacity 512 bits).
+pub const SHAKE256_RATE: usize = 136;
+
+/// Squeezing side of an extendable-output function.
+///
+/// Returned once absorption is finished; each call to [`XofReader::squeeze`] continues the
+/// output stream where the previous call stopped.
+#[derive(Clone)]
+pub struct XofReader {
+    inner: SpongeReader,
+}
+
+impl XofReader {
+    /// Wraps a sponge that has already been padded.
+    pub(crate) fn new(inner: SpongeReader) -> Self {
+        Self { inner }
+    }
+
+    /// Fills `out` with the next `out.len()` bytes of output.
+    pub fn squeeze(&mut self, out: &mut [u8]) {
+        self.inner.squeeze(out);
+    }
+}
+
+/// SHAKE128 in its absorbing phase (128-bit security).
+#[derive(Clone)]
+pub struct Shake128 {
+    sponge: Sponge,
+}
+
+impl Shake128 {
+    /// Creates an empty SHAKE128 instance.
+    pub fn new() -> Self {
+        Self {
+            sponge: Sponge::new(SHAKE128_RATE, DOMAIN_SHAKE),
+        }
+    }
+
+    /// Absorbs `input` into the sponge.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The next chunk of the message (any length, including zero)
+    pub fn absorb(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    /// Finishes absorption and returns the output reader.
+    pub fn finalize(self) -> XofReader {
+        XofReader::new(self.sponge.finalize())
+    }
+}
+
+impl Default for Shake128 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// SHAKE256 in its absorbing phase (256-bit security).
+#[derive(Clone)]
+pub struct Shake256 {
+    sponge: Sponge,
+}
+
+impl Shake256 {
+    /// Creates an empty SHAKE256 instance.
+    pub fn new() -> Self {
+        Self {
+            sponge: Sponge::new(SHAKE256_RATE, DOMAIN_SHAKE),
+        }
+    }
+
+    /// Absorbs `input` into the sponge.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The next chunk of the message (any length, including zero)
+    pub fn absorb(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    /// Finishes absorption and returns the output reader.
+    pub fn finalize(self) -> XofReader {
+        XofReader::new(self.sponge.finalize())
+    }
+}
+
+impl Default for Shake256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes `out_len` bytes of SHAKE128 output for `input`.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+/// * `out_len` - Number of output bytes to produce
+pub fn shake128(input: &[u8], out_len: usize) -> Vec<u8> {
+    let mut xof = Shake128::new();
+    xof.absorb(input);
+
+    let mut out = vec![0u8; out_len];
+    xof.finalize().squeeze(&mut out);
+
+    out
+}
+
+/// Computes `out_len` bytes of SHAKE256 output for `input`.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+/// * `out_len` - Number of output bytes to produce
+pub fn shake256(input: &[u8], out_len: usize) -> Vec<u8> {
+    let mut xof = Shake256::new();
+    xof.absorb(input);
+
+    let mut out = vec![0u8; out_len];
+    xof.finalize().squeeze(&mut out);
+
+    out
+}
@@ -0,0 +1,171 @@
+/// Generic Keccak sponge construction.
+///
+/// Ce module fournit une éponge paramétrable au-dessus de
+/// [`keccak_f1600`](crate::hash::sha3::keccak::keccak_f1600) : le `rate` (en octets) fixe la
+/// partie de l'état exposée aux données, la capacité étant le reste des 200 octets. L'octet de
+/// séparation de domaine encode les bits de suffixe et le premier bit du padding `pad10*1`.
+///
+/// | Construction | Suffixe | Octet de domaine |
+/// |--------------|---------|------------------|
+/// | Keccak       | —       | `0x01`           |
+/// | SHA-3        | `01`    | `0x06`           |
+/// | SHAKE        | `1111`  | `0x1F`           |
+///
+/// L'éponge absorbe via [`Sponge::absorb`], puis [`Sponge::finalize`] applique le padding et
+/// renvoie un [`SpongeReader`] qui peut produire autant d'octets que nécessaire.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::sha3::sponge::{DOMAIN_SHA3, Sponge};
+///
+/// let mut sponge = Sponge::new(136, DOMAIN_SHA3);
+/// sponge.absorb(b"abc");
+///
+/// let mut out = [0u8; 32];
+/// sponge.finalize().squeeze(&mut out);
+/// assert_eq!(out[0], 0x3a);
+/// ```
+use crate::hash::sha3::keccak::keccak_f1600;
+
+/// Domain byte for the original Keccak padding (no suffix).
+pub const DOMAIN_KECCAK: u8 = 0x01;
+
+/// Domain byte for the SHA-3 fixed-output functions (suffix `01`).
+pub const DOMAIN_SHA3: u8 = 0x06;
+
+/// Domain byte for the SHAKE extendable-output functions (suffix `1111`).
+pub const DOMAIN_SHAKE: u8 = 0x1F;
+
+/// Width of the Keccak-f[1600] state in bytes.
+pub const STATE_BYTES: usize = 200;
+
+/// XORs a single byte into the little-endian lane layout of the state.
+#[inline(always)]
+fn xor_byte(state: &mut [u64; 25], pos: usize, byte: u8) {
+    state[pos / 8] ^= (byte as u64) << (8 * (pos % 8));
+}
+
+/// Keccak sponge in its absorbing phase.
+#[derive(Clone)]
+pub struct Sponge {
+    state: [u64; 25],
+    rate: usize,
+    pos: usize,
+    domain: u8,
+}
+
+impl Sponge {
+    /// Creates an empty sponge.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - Number of bytes absorbed or squeezed per permutation (capacity is `200 - rate`)
+    /// * `domain` - Domain separation byte, see the table in the module documentation
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is zero, not a multiple of 8, or not smaller than the 200-byte state.
+    pub fn new(rate: usize, domain: u8) -> Self {
+        assert!(
+            rate > 0 && rate < STATE_BYTES && rate.is_multiple_of(8),
+            "invalid sponge rate"
+        );
+
+        Self {
+            state: [0u64; 25],
+            rate,
+            pos: 0,
+            domain,
+        }
+    }
+
+    /// Returns the rate in bytes.
+    pub fn rate(&self) -> usize {
+        self.rate
+    }
+
+    /// Returns the capacity in bytes.
+    pub fn capacity(&self) -> usize {
+        STATE_BYTES - self.rate
+    }
+
+    /// Absorbs `input` into the sponge, permuting each time a full rate block is filled.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The next chunk of the message (any length, including zero)
+    pub fn absorb(&mut self, input: &[u8]) {
+        let mut input = input;
+
+        while !input.is_empty() {
+            if self.pos == 0 && input.len() >= self.rate {
+                // Fast path: whole block, XORed lane by lane
+                let (block, rest) = input.split_at(self.rate);
+
+                for (lane, chunk) in self.state.iter_mut().zip(block.chunks_exact(8)) {
+                    let mut word = [0u8; 8];
+                    word.copy_from_slice(chunk);
+                    *lane ^= u64::from_le_bytes(word);
+                }
+
+                keccak_f1600(&mut self.state);
+                input = rest;
+                continue;
+            }
+
+            let take = (self.rate - self.pos).min(input.len());
+
+            for (i, &byte) in input[..take].iter().enumerate() {
+                xor_byte(&mut self.state, self.pos + i, byte);
+            }
+
+            self.pos += take;
+            input = &input[take..];
+
+            if self.pos == self.rate {
+                keccak_f1600(&mut self.state);
+                self.pos = 0;
+            }
+        }
+    }
+
+    /// Applies the domain separation and `pad10*1` padding, switching to the squeezing phase.
+    pub fn finalize(mut self) -> SpongeReader {
+        xor_byte(&mut self.state, self.pos, self.domain);
+        xor_byte(&mut self.state, self.rate - 1, 0x80);
+        keccak_f1600(&mut self.state);
+
+        SpongeReader {
+            state: self.state,
+            rate: self.rate,
+            pos: 0,
+        }
+    }
+}
+
+/// Keccak sponge in its squeezing phase.
+///
+/// Produces an unbounded output stream; consecutive [`SpongeReader::squeeze`] calls continue
+/// where the previous one stopped, across rate boundaries.
+#[derive(Clone)]
+pub struct SpongeReader {
+    state: [u64; 25],
+    rate: usize,
+    pos: usize,
+}
+
+impl SpongeReader {
+    /// Fills `out` with the next `out.len()` bytes of sponge output.
+    pub fn squeeze(&mut self, out: &mut [u8]) {
+        for byte in out.iter_mut() {
+            if self.pos == self.rate {
+                keccak_f1600(&mut self.state);
+                self.pos = 0;
+            }
+
+            *byte = (self.state[self.pos / 8] >> (8 * (self.pos % 8))) as u8;
+            self.pos += 1;
+        }
+    }
+}
@@ -0,0 +1,154 @@
+/// SHA-512 computation functions and round operations.
+///
+/// Ce module fournit les helpers bas-niveau pour la compression SHA-512 : opérations bitwise
+/// sur des mots de 64 bits et boucle principale de 80 tours.
+///
+/// # Opérations bitwise
+///
+/// | Fonction        | Rôle             | Formule                                 |
+/// |-----------------|------------------|-----------------------------------------|
+/// | `small_sigma0`  | Expansion mot    | ROTR(1) ^ ROTR(8) ^ SHR(7)              |
+/// | `small_sigma1`  | Expansion mot    | ROTR(19) ^ ROTR(61) ^ SHR(6)            |
+/// | `big_sigma0`    | Rotation état    | ROTR(28) ^ ROTR(34) ^ ROTR(39)          |
+/// | `big_sigma1`    | Rotation état    | ROTR(14) ^ ROTR(18) ^ ROTR(41)          |
+/// | `ch`            | Choix            | (e & f) ^ (!e & g)                      |
+/// | `maj`           | Majorité         | (a & b) ^ (a & c) ^ (b & c)             |
+///
+/// # Références
+///
+/// - FIPS 180-4: Secure Hash Standard (SHS)
+use crate::hash::sha512::K512;
+
+/// Computes the SHA-512 small sigma0 function.
+///
+/// Used in word schedule expansion (W[t] calculation).
+///
+/// # Formula
+///
+/// σ₀(x) = ROTR(1, x) ⊕ ROTR(8, x) ⊕ SHR(7, x)
+#[inline(always)]
+pub fn small_sigma0(x: u64) -> u64 {
+    x.rotate_right(1) ^ x.rotate_right(8) ^ (x >> 7)
+}
+
+/// Computes the SHA-512 small sigma1 function.
+///
+/// Used in word schedule expansion (W[t] calculation).
+///
+/// # Formula
+///
+/// σ₁(x) = ROTR(19, x) ⊕ ROTR(61, x) ⊕ SHR(6, x)
+#[inline(always)]
+pub fn small_sigma1(x: u64) -> u64 {
+    x.rotate_right(19) ^ x.rotate_right(61) ^ (x >> 6)
+}
+
+/// Computes the SHA-512 big sigma0 function.
+///
+/// Used in the main compression loop for state updates.
+///
+/// # Formula
+///
+/// Σ₀(x) = ROTR(28, x) ⊕ ROTR(34, x) ⊕ ROTR(39, x)
+#[inline(always)]
+pub fn big_sigma0(x: u64) -> u64 {
+    x.rotate_right(28) ^ x.rotate_right(34) ^ x.rotate_right(39)
+}
+
+/// Computes the SHA-512 big sigma1 function.
+///
+/// Used in the main compression loop for state updates.
+///
+/// # Formula
+///
+/// Σ₁(x) = ROTR(14, x) ⊕ ROTR(18, x) ⊕ ROTR(41, x)
+#[inline(always)]
+pub fn big_sigma1(x: u64) -> u64 {
+    x.rotate_right(14) ^ x.rotate_right(18) ^ x.rotate_right(41)
+}
+
+/// Computes the SHA-512 choice function on 64-bit words.
+///
+/// # Formula
+///
+/// Ch(e, f, g) = (e ∧ f) ⊕ (¬e ∧ g)
+#[inline(always)]
+pub fn ch(e: u64, f: u64, g: u64) -> u64 {
+    (e & f) ^ ((!e) & g)
+}
+
+/// Computes the SHA-512 majority function on 64-bit words.
+///
+/// # Formula
+///
+/// Maj(a, b, c) = (a ∧ b) ⊕ (a ∧ c) ⊕ (b ∧ c)
+#[inline(always)]
+pub fn maj(a: u64, b: u64, c: u64) -> u64 {
+    (a & b) ^ (a & c) ^ (b & c)
+}
+
+/// Executes all 80 rounds of the SHA-512 compression function.
+///
+/// Same structure as the SHA-256 loop, on 64-bit words and with 80 rounds:
+///
+/// For each round i (0..80):
+/// 1. If i >= 16: Expand the message schedule: W[i] = σ₁(W[i-2]) + W[i-7] + σ₀(W[i-15]) + W[i-16]
+/// 2. Calculate: T1 = h + Σ₁(e) + Ch(e,f,g) + K[i] + W[i]
+/// 3. Calculate: T2 = Σ₀(a) + Maj(a,b,c)
+/// 4. Update: a←T1+T2, e←d+T1, and rotate other variables
+///
+/// # Arguments
+///
+/// * `state` - Current hash state [a, b, c, d, e, f, g, h], updated in-place
+/// * `w` - Message schedule array (16 values, circular buffer)
+pub fn all_rounds(state: &mut [u64; 8], mut w: [u64; 16]) {
+    let mut a = state[0];
+    let mut b = state[1];
+    let mut c = state[2];
+    let mut d = state[3];
+    let mut e = state[4];
+    let mut f = state[5];
+    let mut g = state[6];
+    let mut h = state[7];
+
+    for i in 0..80 {
+        if i >= 16 {
+            // Circular buffer indexing: access W[i-2], W[i-7], W[i-15], W[i-16] via modulo 16
+            let w16 = w[(i - 16) & 15];
+            let w15 = w[(i - 15) & 15];
+            let w7 = w[(i - 7) & 15];
+            let w2 = w[(i - 2) & 15];
+
+            let s0 = small_sigma0(w15);
+            let s1 = small_sigma1(w2);
+
+            w[i & 15] = w16.wrapping_add(s0).wrapping_add(w7).wrapping_add(s1);
+        }
+
+        let t1 = h
+            .wrapping_add(big_sigma1(e))
+            .wrapping_add(ch(e, f, g))
+            .wrapping_add(w[i & 15])
+            .wrapping_add(K512[i]);
+
+        let t2 = big_sigma0(a).wrapping_add(maj(a, b, c));
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+    }
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+    state[4] = state[4].wrapping_add(e);
+    state[5] = state[5].wrapping_add(f);
+    state[6] = state[6].wrapping_add(g);
+    state[7] = state[7].wrapping_add(h);
+}
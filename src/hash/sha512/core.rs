@@ -0,0 +1,156 @@
+/// Core SHA-512 compression and hashing functions.
+///
+/// Ce module contient la fonction de compression de bloc SHA-512 et les fonctions one-shot
+/// de toute la famille. Les variantes ne diffèrent que par leur valeur initiale et la
+/// troncature finale de l'état.
+///
+/// # Algorithme
+///
+/// SHA-512 traite les messages en blocs de 1024 bits (128 octets) :
+/// 1. Initialisation avec la valeur initiale de la variante
+/// 2. Padding et encodage de la longueur sur 128 bits
+/// 3. Compression de chaque bloc
+/// 4. Sortie des premiers octets de l'état
+///
+/// # Fonctions
+///
+/// - [`compress`] : compresse un bloc 1024 bits
+/// - [`sha512`], [`sha384`], [`sha512_224`], [`sha512_256`] : hash d'une entrée de taille arbitraire
+use crate::hash::sha224::H224;
+use crate::hash::sha512::computations::all_rounds;
+use crate::hash::sha512::{H384, H384_INIT, H512, H512_224_INIT, H512_256_INIT, H512_INIT};
+
+use crate::primitives::U256;
+
+/// Compresses a single 1024-bit (128-byte) block using the SHA-512 compression function.
+///
+/// # Arguments
+///
+/// * `block` - A 128-byte block to process
+/// * `state` - The current hash state (8 x 64-bit values), updated in-place
+///
+/// # Details
+///
+/// The function:
+/// 1. Converts the input block into 16 words (64-bit values) in big-endian format
+/// 2. Calls the round computation function with the state and word schedule
+#[inline(always)]
+pub fn compress(block: &[u8; 128], state: &mut [u64; 8]) {
+    let mut w = [0u64; 16];
+
+    for (i, slot) in w.iter_mut().enumerate() {
+        // Read u64 in big-endian format
+        let idx = i * 8;
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&block[idx..idx + 8]);
+        *slot = u64::from_be_bytes(word);
+    }
+
+    all_rounds(state, w);
+}
+
+/// Serializes the first `N` bytes of a SHA-512 state in big-endian order.
+pub(crate) fn truncate<const N: usize>(state: &[u64; 8]) -> [u8; N] {
+    let mut full = [0u8; 64];
+
+    for (i, v) in state.iter().enumerate() {
+        full[i * 8..i * 8 + 8].copy_from_slice(&v.to_be_bytes());
+    }
+
+    let mut out = [0u8; N];
+    out.copy_from_slice(&full[..N]);
+
+    out
+}
+
+/// Runs the full SHA-512 padding and compression over `input` from the given IV.
+///
+/// # Algorithm Steps
+///
+/// 1. **Block Processing**: Iterates through complete 128-byte blocks, compressing each
+/// 2. **Padding**: Appends 0x80, then zeros up to 112 bytes (mod 128), adding a block if needed
+/// 3. **Length Encoding**: Appends the message length in bits (128-bit big-endian)
+/// 4. **Final Compression**: Processes the padded final block(s)
+fn hash_with(init: [u64; 8], input: &[u8]) -> [u64; 8] {
+    let mut state = init;
+
+    let mut i = 0;
+    let len = input.len();
+
+    while i + 128 <= len {
+        let block: &[u8; 128] = input[i..i + 128].try_into().unwrap();
+        compress(block, &mut state);
+        i += 128;
+    }
+
+    let mut block = [0u8; 128];
+    let rem = len - i;
+
+    block[..rem].copy_from_slice(&input[i..]);
+    block[rem] = 0x80; // SHA-512 padding bit
+
+    if rem > 111 {
+        // Need extra block for message length
+        compress(&block, &mut state);
+        block = [0; 128];
+    }
+
+    let bit_len = (len as u128) << 3;
+    block[112..128].copy_from_slice(&bit_len.to_be_bytes());
+
+    compress(&block, &mut state);
+
+    state
+}
+
+/// Computes the SHA-512 hash of the input data.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// An [`H512`] containing the 512-bit hash output
+pub fn sha512(input: &[u8]) -> H512 {
+    H512(truncate(&hash_with(H512_INIT, input)))
+}
+
+/// Computes the SHA-384 hash of the input data.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// An [`H384`] containing the 384-bit hash output
+pub fn sha384(input: &[u8]) -> H384 {
+    H384(truncate(&hash_with(H384_INIT, input)))
+}
+
+/// Computes the SHA-512/224 hash of the input data.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// An [`H224`] containing the 224-bit hash output
+pub fn sha512_224(input: &[u8]) -> H224 {
+    H224(truncate(&hash_with(H512_224_INIT, input)))
+}
+
+/// Computes the SHA-512/256 hash of the input data.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash (any length, including zero)
+///
+/// # Returns
+///
+/// A [`U256`] containing the 256-bit hash output
+pub fn sha512_256(input: &[u8]) -> U256 {
+    U256(truncate(&hash_with(H512_256_INIT, input)))
+}
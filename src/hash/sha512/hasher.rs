@@ -0,0 +1,233 @@
+/// Incremental SHA-512 family hashers.
+///
+/// Ce module fournit [`Sha512`] et ses variantes tronquées [`Sha384`], [`Sha512_224`] et
+/// [`Sha512_256`]. Les résultats sont identiques aux fonctions one-shot de
+/// [`core`](crate::hash::sha512::core) quel que soit le découpage de l'entrée.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::{Sha512, sha512};
+///
+/// let mut hasher = Sha512::new();
+/// hasher.update(b"hello ");
+/// hasher.update(b"world");
+///
+/// assert_eq!(hasher.finalize(), sha512(b"hello world"));
+/// ```
+use crate::hash::sha224::H224;
+use crate::hash::sha512::core::{compress, truncate};
+use crate::hash::sha512::{H384, H384_INIT, H512, H512_224_INIT, H512_256_INIT, H512_INIT};
+
+use crate::primitives::U256;
+
+/// Streaming SHA-512 state.
+///
+/// Holds the chaining value, a 128-byte buffer for the pending partial block and the total
+/// number of bytes absorbed so far. Cloning a hasher forks the computation.
+#[derive(Clone)]
+pub struct Sha512 {
+    state: [u64; 8],
+    buffer: [u8; 128],
+    buffer_len: usize,
+    length: u128,
+}
+
+impl Sha512 {
+    /// Creates a hasher initialized with [`H512_INIT`].
+    pub fn new() -> Self {
+        Self::with_state(H512_INIT)
+    }
+
+    /// Creates a hasher starting from an arbitrary chaining value.
+    pub(crate) fn with_state(state: [u64; 8]) -> Self {
+        Self {
+            state,
+            buffer: [0u8; 128],
+            buffer_len: 0,
+            length: 0,
+        }
+    }
+
+    /// Absorbs `input` into the hash state.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The next chunk of the message (any length, including zero)
+    pub fn update(&mut self, input: &[u8]) {
+        let mut input = input;
+        self.length = self.length.wrapping_add(input.len() as u128);
+
+        if self.buffer_len > 0 {
+            // Top up the pending block first
+            let take = (128 - self.buffer_len).min(input.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&input[..take]);
+            self.buffer_len += take;
+            input = &input[take..];
+
+            if self.buffer_len < 128 {
+                return;
+            }
+
+            compress(&self.buffer, &mut self.state);
+            self.buffer_len = 0;
+        }
+
+        let mut blocks = input.chunks_exact(128);
+
+        for block in &mut blocks {
+            // chunks_exact guarantees 128 bytes
+            let block: &[u8; 128] = block.try_into().unwrap();
+            compress(block, &mut self.state);
+        }
+
+        let rem = blocks.remainder();
+        self.buffer[..rem.len()].copy_from_slice(rem);
+        self.buffer_len = rem.len();
+    }
+
+    /// Applies the final padding and returns the raw chaining value.
+    pub(crate) fn finalize_state(mut self) -> [u64; 8] {
+        let bit_len = self.length << 3;
+        let rem = self.buffer_len;
+
+        let mut block = [0u8; 128];
+        block[..rem].copy_from_slice(&self.buffer[..rem]);
+        block[rem] = 0x80; // SHA-512 padding bit
+
+        if rem > 111 {
+            // Need extra block for message length
+            compress(&block, &mut self.state);
+            block = [0; 128];
+        }
+
+        block[112..128].copy_from_slice(&bit_len.to_be_bytes());
+        compress(&block, &mut self.state);
+
+        self.state
+    }
+
+    /// Consumes the hasher and returns the SHA-512 digest of everything absorbed.
+    pub fn finalize(self) -> H512 {
+        H512(truncate(&self.finalize_state()))
+    }
+
+    /// Resets the hasher to its initial state, discarding any absorbed data.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for Sha512 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Streaming SHA-384 state, seeded with [`H384_INIT`].
+#[derive(Clone)]
+pub struct Sha384 {
+    inner: Sha512,
+}
+
+impl Sha384 {
+    /// Creates a hasher initialized with [`H384_INIT`].
+    pub fn new() -> Self {
+        Self {
+            inner: Sha512::with_state(H384_INIT),
+        }
+    }
+
+    /// Absorbs `input` into the hash state.
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    /// Consumes the hasher and returns the SHA-384 digest of everything absorbed.
+    pub fn finalize(self) -> H384 {
+        H384(truncate(&self.inner.finalize_state()))
+    }
+
+    /// Resets the hasher to its initial state, discarding any absorbed data.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for Sha384 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Streaming SHA-512/224 state, seeded with [`H512_224_INIT`].
+#[derive(Clone)]
+pub struct Sha512_224 {
+    inner: Sha512,
+}
+
+impl Sha512_224 {
+    /// Creates a hasher initialized with [`H512_224_INIT`].
+    pub fn new() -> Self {
+        Self {
+            inner: Sha512::with_state(H512_224_INIT),
+        }
+    }
+
+    /// Absorbs `input` into the hash state.
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    /// Consumes the hasher and returns the SHA-512/224 digest of everything absorbed.
+    pub fn finalize(self) -> H224 {
+        H224(truncate(&self.inner.finalize_state()))
+    }
+
+    /// Resets the hasher to its initial state, discarding any absorbed data.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for Sha512_224 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Streaming SHA-512/256 state, seeded with [`H512_256_INIT`].
+#[derive(Clone)]
+pub struct Sha512_256 {
+    inner: Sha512,
+}
+
+impl Sha512_256 {
+    /// Creates a hasher initialized with [`H512_256_INIT`].
+    pub fn new() -> Self {
+        Self {
+            inner: Sha512::with_state(H512_256_INIT),
+        }
+    }
+
+    /// Absorbs `input` into the hash state.
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    /// Consumes the hasher and returns the SHA-512/256 digest of everything absorbed.
+    pub fn finalize(self) -> U256 {
+        U256(truncate(&self.inner.finalize_state()))
+    }
+
+    /// Resets the hasher to its initial state, discarding any absorbed data.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for Sha512_256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,145 @@
+/// cSHAKE128 and cSHAKE256 customizable extendable-output functions.
+///
+/// cSHAKE ajoute à SHAKE une chaîne de nom de fonction `N` (réservée aux fonctions définies
+/// par le NIST, comme `"KMAC"`) et une chaîne de personnalisation `S` choisie par l'application.
+/// Lorsque `N` et `S` sont vides, cSHAKE est strictement identique à SHAKE.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::sp800_185::cshake::CShake128;
+///
+/// let mut xof = CShake128::new(b"", b"Nebula node id");
+/// xof.absorb(b"public key bytes");
+///
+/// let mut id = [0u8; 32];
+/// xof.finalize().squeeze(&mut id);
+/// ```
+use crate::hash::sha3::shake::{SHAKE128_RATE, SHAKE256_RATE, XofReader};
+use crate::hash::sha3::sponge::{DOMAIN_SHAKE, Sponge};
+use crate::hash::sp800_185::encoding::{bytepad, encode_string};
+
+/// Domain byte for cSHAKE with a non-empty name or customization (suffix `00`).
+pub const DOMAIN_CSHAKE: u8 = 0x04;
+
+/// Builds a sponge that has already absorbed `bytepad(encode_string(N) ‖ encode_string(S), rate)`.
+pub(crate) fn cshake_sponge(rate: usize, function_name: &[u8], customization: &[u8]) -> Sponge {
+    if function_name.is_empty() && customization.is_empty() {
+        // cSHAKE degenerates to plain SHAKE
+        return Sponge::new(rate, DOMAIN_SHAKE);
+    }
+
+    let mut prefix = encode_string(function_name);
+    prefix.extend_from_slice(&encode_string(customization));
+
+    let mut sponge = Sponge::new(rate, DOMAIN_CSHAKE);
+    sponge.absorb(&bytepad(&prefix, rate));
+
+    sponge
+}
+
+/// cSHAKE128 in its absorbing phase.
+#[derive(Clone)]
+pub struct CShake128 {
+    sponge: Sponge,
+}
+
+impl CShake128 {
+    /// Creates a cSHAKE128 instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `function_name` - NIST function-name string `N` (empty for application use)
+    /// * `customization` - Customization string `S`
+    pub fn new(function_name: &[u8], customization: &[u8]) -> Self {
+        Self {
+            sponge: cshake_sponge(SHAKE128_RATE, function_name, customization),
+        }
+    }
+
+    /// Absorbs `input` into the sponge.
+    pub fn absorb(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    /// Finishes absorption and returns the output reader.
+    pub fn finalize(self) -> XofReader {
+        XofReader::new(self.sponge.finalize())
+    }
+}
+
+/// cSHAKE256 in its absorbing phase.
+#[derive(Clone)]
+pub struct CShake256 {
+    sponge: Sponge,
+}
+
+impl CShake256 {
+    /// Creates a cSHAKE256 instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `function_name` - NIST function-name string `N` (empty for application use)
+    /// * `customization` - Customization string `S`
+    pub fn new(function_name: &[u8], customization: &[u8]) -> Self {
+        Self {
+            sponge: cshake_sponge(SHAKE256_RATE, function_name, customization),
+        }
+    }
+
+    /// Absorbs `input` into the sponge.
+    pub fn absorb(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    /// Finishes absorption and returns the output reader.
+    pub fn finalize(self) -> XofReader {
+        XofReader::new(self.sponge.finalize())
+    }
+}
+
+/// Computes `out_len` bytes of cSHAKE128 output.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash
+/// * `function_name` - NIST function-name string `N`
+/// * `customization` - Customization string `S`
+/// * `out_len` - Number of output bytes to produce
+pub fn cshake128(
+    input: &[u8],
+    function_name: &[u8],
+    customization: &[u8],
+    out_len: usize,
+) -> Vec<u8> {
+    let mut xof = CShake128::new(function_name, customization);
+    xof.absorb(input);
+
+    let mut out = vec![0u8; out_len];
+    xof.finalize().squeeze(&mut out);
+
+    out
+}
+
+/// Computes `out_len` bytes of cSHAKE256 output.
+///
+/// # Arguments
+///
+/// * `input` - The input data to hash
+/// * `function_name` - NIST function-name string `N`
+/// * `customization` - Customization string `S`
+/// * `out_len` - Number of output bytes to produce
+pub fn cshake256(
+    input: &[u8],
+    function_name: &[u8],
+    customization: &[u8],
+    out_len: usize,
+) -> Vec<u8> {
+    let mut xof = CShake256::new(function_name, customization);
+    xof.absorb(input);
+
+    let mut out = vec![0u8; out_len];
+    xof.finalize().squeeze(&mut out);
+
+    out
+}
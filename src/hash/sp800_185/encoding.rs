@@ -0,0 +1,82 @@
+//! Integer and string encodings from NIST SP 800-185 §2.3.
+//!
+//! Ce module fournit les encodages non ambigus utilisés par toutes les fonctions dérivées de
+//! SHA-3. Les longueurs sont exprimées en bits, comme dans la spécification.
+//!
+//! | Fonction        | Sortie                                             |
+//! |-----------------|----------------------------------------------------|
+//! | `left_encode`   | `n` ‖ x sur `n` octets (big-endian)                |
+//! | `right_encode`  | x sur `n` octets (big-endian) ‖ `n`                |
+//! | `encode_string` | `left_encode(8·len(S))` ‖ S                         |
+//! | `bytepad`       | `left_encode(w)` ‖ X ‖ zéros jusqu'à un multiple de `w` |
+
+/// Returns the minimal big-endian byte representation of `x` (at least one byte).
+fn minimal_be(x: u64) -> ([u8; 8], usize) {
+    let bytes = x.to_be_bytes();
+    let n = (8 - (x.leading_zeros() as usize / 8)).max(1);
+
+    (bytes, n)
+}
+
+/// Encodes `x` with its byte length prepended.
+///
+/// # Example
+///
+/// ```
+/// use cryptography::hash::sp800_185::encoding::left_encode;
+/// assert_eq!(left_encode(0), vec![1, 0]);
+/// assert_eq!(left_encode(168), vec![1, 168]);
+/// assert_eq!(left_encode(256), vec![2, 1, 0]);
+/// ```
+pub fn left_encode(x: u64) -> Vec<u8> {
+    let (bytes, n) = minimal_be(x);
+
+    let mut out = Vec::with_capacity(n + 1);
+    out.push(n as u8);
+    out.extend_from_slice(&bytes[8 - n..]);
+
+    out
+}
+
+/// Encodes `x` with its byte length appended.
+///
+/// # Example
+///
+/// ```
+/// use cryptography::hash::sp800_185::encoding::right_encode;
+/// assert_eq!(right_encode(0), vec![0, 1]);
+/// assert_eq!(right_encode(256), vec![1, 0, 2]);
+/// ```
+pub fn right_encode(x: u64) -> Vec<u8> {
+    let (bytes, n) = minimal_be(x);
+
+    let mut out = Vec::with_capacity(n + 1);
+    out.extend_from_slice(&bytes[8 - n..]);
+    out.push(n as u8);
+
+    out
+}
+
+/// Encodes a byte string as its bit length (`left_encode`) followed by the string itself.
+pub fn encode_string(s: &[u8]) -> Vec<u8> {
+    let mut out = left_encode((s.len() as u64) << 3);
+    out.extend_from_slice(s);
+
+    out
+}
+
+/// Prepends `left_encode(w)` to `x` and zero-pads the result to a multiple of `w` bytes.
+///
+/// # Arguments
+///
+/// * `x` - The data to pad
+/// * `w` - The block width in bytes (the sponge rate); must be non-zero
+pub fn bytepad(x: &[u8], w: usize) -> Vec<u8> {
+    let mut out = left_encode(w as u64);
+    out.extend_from_slice(x);
+
+    let padded = out.len().div_ceil(w) * w;
+    out.resize(padded, 0);
+
+    out
+}
@@ -0,0 +1,145 @@
+/// KMAC128 and KMAC256 keyed message authentication codes.
+///
+/// KMAC est un MAC construit directement sur cSHAKE (nom de fonction `"KMAC"`) : la clé est
+/// absorbée dans un bloc complet via `bytepad`, suivie du message puis de la longueur de sortie
+/// demandée. La variante XOF (`finalize_xof`) encode une longueur nulle, si bien que la sortie
+/// ne dépend pas de la quantité d'octets extraite.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::sp800_185::kmac::{Kmac128, kmac128};
+///
+/// let mut mac = Kmac128::new(b"key", b"");
+/// mac.update(b"hello ");
+/// mac.update(b"world");
+///
+/// let mut tag = [0u8; 32];
+/// mac.finalize(&mut tag);
+/// assert_eq!(tag.to_vec(), kmac128(b"key", b"hello world", b"", 32));
+/// ```
+use crate::hash::sha3::shake::{SHAKE128_RATE, SHAKE256_RATE, XofReader};
+use crate::hash::sha3::sponge::Sponge;
+use crate::hash::sp800_185::cshake::cshake_sponge;
+use crate::hash::sp800_185::encoding::{bytepad, encode_string, right_encode};
+
+/// Function-name string reserved for KMAC.
+const KMAC_NAME: &[u8] = b"KMAC";
+
+/// Builds the cSHAKE sponge keyed with `bytepad(encode_string(K), rate)`.
+fn keyed_sponge(rate: usize, key: &[u8], customization: &[u8]) -> Sponge {
+    let mut sponge = cshake_sponge(rate, KMAC_NAME, customization);
+    sponge.absorb(&bytepad(&encode_string(key), rate));
+
+    sponge
+}
+
+/// Streaming KMAC128 state.
+#[derive(Clone)]
+pub struct Kmac128 {
+    sponge: Sponge,
+}
+
+impl Kmac128 {
+    /// Creates a KMAC128 instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The secret key (any length; at least 16 bytes recommended)
+    /// * `customization` - Customization string `S`
+    pub fn new(key: &[u8], customization: &[u8]) -> Self {
+        Self {
+            sponge: keyed_sponge(SHAKE128_RATE, key, customization),
+        }
+    }
+
+    /// Absorbs `input` into the MAC state.
+    pub fn update(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    /// Writes a tag of `out.len()` bytes; the requested length is bound into the tag.
+    pub fn finalize(mut self, out: &mut [u8]) {
+        self.sponge.absorb(&right_encode((out.len() as u64) << 3));
+        self.sponge.finalize().squeeze(out);
+    }
+
+    /// Finishes the MAC in XOF mode (KMACXOF128) and returns the output reader.
+    pub fn finalize_xof(mut self) -> XofReader {
+        self.sponge.absorb(&right_encode(0));
+        XofReader::new(self.sponge.finalize())
+    }
+}
+
+/// Streaming KMAC256 state.
+#[derive(Clone)]
+pub struct Kmac256 {
+    sponge: Sponge,
+}
+
+impl Kmac256 {
+    /// Creates a KMAC256 instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The secret key (any length; at least 32 bytes recommended)
+    /// * `customization` - Customization string `S`
+    pub fn new(key: &[u8], customization: &[u8]) -> Self {
+        Self {
+            sponge: keyed_sponge(SHAKE256_RATE, key, customization),
+        }
+    }
+
+    /// Absorbs `input` into the MAC state.
+    pub fn update(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    /// Writes a tag of `out.len()` bytes; the requested length is bound into the tag.
+    pub fn finalize(mut self, out: &mut [u8]) {
+        self.sponge.absorb(&right_encode((out.len() as u64) << 3));
+        self.sponge.finalize().squeeze(out);
+    }
+
+    /// Finishes the MAC in XOF mode (KMACXOF256) and returns the output reader.
+    pub fn finalize_xof(mut self) -> XofReader {
+        self.sponge.absorb(&right_encode(0));
+        XofReader::new(self.sponge.finalize())
+    }
+}
+
+/// Computes a KMAC128 tag of `out_len` bytes.
+///
+/// # Arguments
+///
+/// * `key` - The secret key
+/// * `input` - The message to authenticate
+/// * `customization` - Customization string `S`
+/// * `out_len` - Tag length in bytes
+pub fn kmac128(key: &[u8], input: &[u8], customization: &[u8], out_len: usize) -> Vec<u8> {
+    let mut mac = Kmac128::new(key, customization);
+    mac.update(input);
+
+    let mut out = vec![0u8; out_len];
+    mac.finalize(&mut out);
+
+    out
+}
+
+/// Computes a KMAC256 tag of `out_len` bytes.
+///
+/// # Arguments
+///
+/// * `key` - The secret key
+/// * `input` - The message to authenticate
+/// * `customization` - Customization string `S`
+/// * `out_len` - Tag length in bytes
+pub fn kmac256(key: &[u8], input: &[u8], customization: &[u8], out_len: usize) -> Vec<u8> {
+    let mut mac = Kmac256::new(key, customization);
+    mac.update(input);
+
+    let mut out = vec![0u8; out_len];
+    mac.finalize(&mut out);
+
+    out
+}
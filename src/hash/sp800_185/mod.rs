@@ -0,0 +1,32 @@
+/// NIST SP 800-185 derived functions.
+///
+/// Ce module fournit les fonctions personnalisables construites sur l'éponge Keccak de
+/// [`sha3`](crate::hash::sha3) : cSHAKE, KMAC, TupleHash et ParallelHash. Elles permettent une
+/// séparation de domaine explicite entre les sous-systèmes grâce aux chaînes de nom de fonction
+/// (`N`) et de personnalisation (`S`).
+///
+/// # Modules
+///
+/// - [`encoding`] : `left_encode`, `right_encode`, `encode_string`, `bytepad`
+/// - [`cshake`] : cSHAKE128 / cSHAKE256
+/// - [`kmac`] : KMAC128 / KMAC256 (MAC et XOF)
+/// - [`tuple_hash`] : TupleHash128 / TupleHash256
+/// - [`parallel_hash`] : ParallelHash128 / ParallelHash256
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::sp800_185::kmac::kmac256;
+///
+/// let tag = kmac256(b"shared key", b"message", b"Nebula session", 32);
+/// assert_eq!(tag.len(), 32);
+/// ```
+///
+/// # Références
+///
+/// - NIST SP 800-185: SHA-3 Derived Functions
+pub mod cshake;
+pub mod encoding;
+pub mod kmac;
+pub mod parallel_hash;
+pub mod tuple_hash;
@@ -0,0 +1,275 @@
+/// ParallelHash128 and ParallelHash256.
+///
+/// ParallelHash découpe le message en blocs de `B` octets, hache chaque bloc indépendamment
+/// avec cSHAKE (sans nom ni personnalisation), puis absorbe la concaténation des empreintes
+/// dans un cSHAKE externe de nom `"ParallelHash"`. Les blocs étant indépendants, les grandes
+/// mises à jour sont réparties sur plusieurs threads via [`std::thread::scope`] ; le résultat
+/// ne dépend ni du nombre de threads ni du découpage des appels à `update`.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::sp800_185::parallel_hash::{ParallelHash128, parallel_hash128};
+///
+/// let mut hasher = ParallelHash128::new(8, b"");
+/// hasher.update(b"0123456789");
+/// hasher.update(b"abcdef");
+///
+/// let mut out = [0u8; 32];
+/// hasher.finalize(&mut out);
+/// assert_eq!(out.to_vec(), parallel_hash128(b"0123456789abcdef", 8, b"", 32));
+/// ```
+use crate::hash::sha3::shake::{SHAKE128_RATE, SHAKE256_RATE, XofReader};
+use crate::hash::sha3::sponge::{DOMAIN_SHAKE, Sponge};
+use crate::hash::sp800_185::cshake::cshake_sponge;
+use crate::hash::sp800_185::encoding::{left_encode, right_encode};
+
+use std::thread;
+
+/// Function-name string reserved for ParallelHash.
+const PARALLEL_HASH_NAME: &[u8] = b"ParallelHash";
+
+/// Minimum number of whole blocks in a single update before worker threads are spawned.
+const PARALLEL_THRESHOLD: usize = 64;
+
+/// Hashes one block with cSHAKE(block, "", "") — i.e. plain SHAKE — into `out`.
+fn leaf(block: &[u8], rate: usize, out: &mut [u8]) {
+    let mut sponge = Sponge::new(rate, DOMAIN_SHAKE);
+    sponge.absorb(block);
+    sponge.finalize().squeeze(out);
+}
+
+/// Shared state of both ParallelHash widths.
+#[derive(Clone)]
+struct ParallelState {
+    sponge: Sponge,
+    rate: usize,
+    leaf_len: usize,
+    block_size: usize,
+    buffer: Vec<u8>,
+    blocks: u64,
+}
+
+impl ParallelState {
+    fn new(rate: usize, leaf_len: usize, block_size: usize, customization: &[u8]) -> Self {
+        assert!(block_size > 0, "ParallelHash block size must be non-zero");
+
+        let mut sponge = cshake_sponge(rate, PARALLEL_HASH_NAME, customization);
+        sponge.absorb(&left_encode(block_size as u64));
+
+        Self {
+            sponge,
+            rate,
+            leaf_len,
+            block_size,
+            buffer: Vec::with_capacity(block_size),
+            blocks: 0,
+        }
+    }
+
+    /// Hashes consecutive whole blocks and absorbs their digests in order.
+    fn absorb_blocks(&mut self, data: &[u8]) {
+        let count = data.len() / self.block_size;
+        let mut digests = vec![0u8; count * self.leaf_len];
+
+        // Only ask the OS for the core count once the update is large enough to split
+        let workers = if count >= PARALLEL_THRESHOLD {
+            thread::available_parallelism().map_or(1, |n| n.get())
+        } else {
+            1
+        };
+
+        if workers > 1 {
+            let per_worker = count.div_ceil(workers);
+            let (rate, leaf_len, block_size) = (self.rate, self.leaf_len, self.block_size);
+
+            thread::scope(|scope| {
+                for (inputs, outputs) in data
+                    .chunks(per_worker * block_size)
+                    .zip(digests.chunks_mut(per_worker * leaf_len))
+                {
+                    scope.spawn(move || {
+                        for (block, out) in inputs
+                            .chunks_exact(block_size)
+                            .zip(outputs.chunks_exact_mut(leaf_len))
+                        {
+                            leaf(block, rate, out);
+                        }
+                    });
+                }
+            });
+        } else {
+            for (block, out) in data
+                .chunks_exact(self.block_size)
+                .zip(digests.chunks_exact_mut(self.leaf_len))
+            {
+                leaf(block, self.rate, out);
+            }
+        }
+
+        self.sponge.absorb(&digests);
+        self.blocks += count as u64;
+    }
+
+    fn update(&mut self, input: &[u8]) {
+        let mut input = input;
+
+        if !self.buffer.is_empty() {
+            let take = (self.block_size - self.buffer.len()).min(input.len());
+            self.buffer.extend_from_slice(&input[..take]);
+            input = &input[take..];
+
+            if self.buffer.len() < self.block_size {
+                return;
+            }
+
+            let block = std::mem::take(&mut self.buffer);
+            self.absorb_blocks(&block);
+            self.buffer = block;
+            self.buffer.clear();
+        }
+
+        let whole = input.len() - input.len() % self.block_size;
+        if whole > 0 {
+            self.absorb_blocks(&input[..whole]);
+        }
+
+        self.buffer.extend_from_slice(&input[whole..]);
+    }
+
+    /// Flushes the last partial block and absorbs `right_encode(n) ‖ right_encode(L)`.
+    fn close(mut self, out_bits: u64) -> Sponge {
+        if !self.buffer.is_empty() {
+            let mut digest = vec![0u8; self.leaf_len];
+            leaf(&self.buffer, self.rate, &mut digest);
+
+            self.sponge.absorb(&digest);
+            self.blocks += 1;
+        }
+
+        self.sponge.absorb(&right_encode(self.blocks));
+        self.sponge.absorb(&right_encode(out_bits));
+
+        self.sponge
+    }
+}
+
+/// Streaming ParallelHash128 state.
+#[derive(Clone)]
+pub struct ParallelHash128 {
+    inner: ParallelState,
+}
+
+impl ParallelHash128 {
+    /// Creates a ParallelHash128 instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_size` - Block size `B` in bytes
+    /// * `customization` - Customization string `S`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_size` is zero.
+    pub fn new(block_size: usize, customization: &[u8]) -> Self {
+        Self {
+            inner: ParallelState::new(SHAKE128_RATE, 32, block_size, customization),
+        }
+    }
+
+    /// Absorbs `input` into the hash state.
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    /// Writes `out.len()` bytes of output; the requested length is bound into the result.
+    pub fn finalize(self, out: &mut [u8]) {
+        let sponge = self.inner.close((out.len() as u64) << 3);
+        sponge.finalize().squeeze(out);
+    }
+
+    /// Finishes in XOF mode (ParallelHashXOF128) and returns the output reader.
+    pub fn finalize_xof(self) -> XofReader {
+        XofReader::new(self.inner.close(0).finalize())
+    }
+}
+
+/// Streaming ParallelHash256 state.
+#[derive(Clone)]
+pub struct ParallelHash256 {
+    inner: ParallelState,
+}
+
+impl ParallelHash256 {
+    /// Creates a ParallelHash256 instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_size` - Block size `B` in bytes
+    /// * `customization` - Customization string `S`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_size` is zero.
+    pub fn new(block_size: usize, customization: &[u8]) -> Self {
+        Self {
+            inner: ParallelState::new(SHAKE256_RATE, 64, block_size, customization),
+        }
+    }
+
+    /// Absorbs `input` into the hash state.
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    /// Writes `out.len()` bytes of output; the requested length is bound into the result.
+    pub fn finalize(self, out: &mut [u8]) {
+        let sponge = self.inner.close((out.len() as u64) << 3);
+        sponge.finalize().squeeze(out);
+    }
+
+    /// Finishes in XOF mode (ParallelHashXOF256) and returns the output reader.
+    pub fn finalize_xof(self) -> XofReader {
+        XofReader::new(self.inner.close(0).finalize())
+    }
+}
+
+/// Computes `out_len` bytes of ParallelHash128 over `input` with block size `block_size`.
+///
+/// # Panics
+///
+/// Panics if `block_size` is zero.
+pub fn parallel_hash128(
+    input: &[u8],
+    block_size: usize,
+    customization: &[u8],
+    out_len: usize,
+) -> Vec<u8> {
+    let mut hasher = ParallelHash128::new(block_size, customization);
+    hasher.update(input);
+
+    let mut out = vec![0u8; out_len];
+    hasher.finalize(&mut out);
+
+    out
+}
+
+/// Computes `out_len` bytes of ParallelHash256 over `input` with block size `block_size`.
+///
+/// # Panics
+///
+/// Panics if `block_size` is zero.
+pub fn parallel_hash256(
+    input: &[u8],
+    block_size: usize,
+    customization: &[u8],
+    out_len: usize,
+) -> Vec<u8> {
+    let mut hasher = ParallelHash256::new(block_size, customization);
+    hasher.update(input);
+
+    let mut out = vec![0u8; out_len];
+    hasher.finalize(&mut out);
+
+    out
+}
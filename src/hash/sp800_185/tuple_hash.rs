@@ -0,0 +1,118 @@
+/// TupleHash128 and TupleHash256.
+///
+/// TupleHash hache une séquence de chaînes de façon non ambiguë : chaque élément est encodé
+/// avec `encode_string`, si bien que `("ab", "c")` et `("a", "bc")` donnent des sorties
+/// différentes. Les éléments sont ajoutés un par un via `push`.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::sp800_185::tuple_hash::tuple_hash128;
+///
+/// let a = tuple_hash128(&[b"ab", b"c"], b"", 32);
+/// let b = tuple_hash128(&[b"a", b"bc"], b"", 32);
+/// assert_ne!(a, b);
+/// ```
+use crate::hash::sha3::shake::{SHAKE128_RATE, SHAKE256_RATE, XofReader};
+use crate::hash::sha3::sponge::Sponge;
+use crate::hash::sp800_185::cshake::cshake_sponge;
+use crate::hash::sp800_185::encoding::{left_encode, right_encode};
+
+/// Function-name string reserved for TupleHash.
+const TUPLE_HASH_NAME: &[u8] = b"TupleHash";
+
+/// Absorbs `encode_string(item)` without copying the item.
+fn absorb_item(sponge: &mut Sponge, item: &[u8]) {
+    sponge.absorb(&left_encode((item.len() as u64) << 3));
+    sponge.absorb(item);
+}
+
+/// Streaming TupleHash128 state.
+#[derive(Clone)]
+pub struct TupleHash128 {
+    sponge: Sponge,
+}
+
+impl TupleHash128 {
+    /// Creates an empty TupleHash128 instance with customization string `S`.
+    pub fn new(customization: &[u8]) -> Self {
+        Self {
+            sponge: cshake_sponge(SHAKE128_RATE, TUPLE_HASH_NAME, customization),
+        }
+    }
+
+    /// Appends one element to the tuple.
+    pub fn push(&mut self, item: &[u8]) {
+        absorb_item(&mut self.sponge, item);
+    }
+
+    /// Writes `out.len()` bytes of output; the requested length is bound into the result.
+    pub fn finalize(mut self, out: &mut [u8]) {
+        self.sponge.absorb(&right_encode((out.len() as u64) << 3));
+        self.sponge.finalize().squeeze(out);
+    }
+
+    /// Finishes in XOF mode (TupleHashXOF128) and returns the output reader.
+    pub fn finalize_xof(mut self) -> XofReader {
+        self.sponge.absorb(&right_encode(0));
+        XofReader::new(self.sponge.finalize())
+    }
+}
+
+/// Streaming TupleHash256 state.
+#[derive(Clone)]
+pub struct TupleHash256 {
+    sponge: Sponge,
+}
+
+impl TupleHash256 {
+    /// Creates an empty TupleHash256 instance with customization string `S`.
+    pub fn new(customization: &[u8]) -> Self {
+        Self {
+            sponge: cshake_sponge(SHAKE256_RATE, TUPLE_HASH_NAME, customization),
+        }
+    }
+
+    /// Appends one element to the tuple.
+    pub fn push(&mut self, item: &[u8]) {
+        absorb_item(&mut self.sponge, item);
+    }
+
+    /// Writes `out.len()` bytes of output; the requested length is bound into the result.
+    pub fn finalize(mut self, out: &mut [u8]) {
+        self.sponge.absorb(&right_encode((out.len() as u64) << 3));
+        self.sponge.finalize().squeeze(out);
+    }
+
+    /// Finishes in XOF mode (TupleHashXOF256) and returns the output reader.
+    pub fn finalize_xof(mut self) -> XofReader {
+        self.sponge.absorb(&right_encode(0));
+        XofReader::new(self.sponge.finalize())
+    }
+}
+
+/// Computes `out_len` bytes of TupleHash128 over `items`.
+pub fn tuple_hash128(items: &[&[u8]], customization: &[u8], out_len: usize) -> Vec<u8> {
+    let mut hasher = TupleHash128::new(customization);
+    for item in items {
+        hasher.push(item);
+    }
+
+    let mut out = vec![0u8; out_len];
+    hasher.finalize(&mut out);
+
+    out
+}
+
+/// Computes `out_len` bytes of TupleHash256 over `items`.
+pub fn tuple_hash256(items: &[&[u8]], customization: &[u8], out_len: usize) -> Vec<u8> {
+    let mut hasher = TupleHash256::new(customization);
+    for item in items {
+        hasher.push(item);
+    }
+
+    let mut out = vec![0u8; out_len];
+    hasher.finalize(&mut out);
+
+    out
+}
@@ -0,0 +1,740 @@
+/// Argon2 memory-hard password hashing (Argon2d, Argon2i, Argon2id).
+///
+/// Ce module fournit Argon2 version 1.3 (RFC 9106), le vainqueur de la Password Hashing
+/// Competition, construit sur [`BLAKE2b`](crate::hash::blake2::blake2b) :
+///
+/// - [`Algorithm`] : Argon2d (adressage dépendant des données), Argon2i (indépendant) ou
+///   Argon2id (hybride, recommandé pour le stockage de mots de passe)
+/// - [`Argon2`] : builder pour les coûts mémoire (`m`, en KiB), temps (`t`, nombre de passes)
+///   et parallélisme (`p`, nombre de lanes), le secret `K`, les données associées `X` et la
+///   longueur du tag
+/// - [`argon2id`] : raccourci avec les paramètres par défaut
+///
+/// La mémoire est une matrice de `p` lanes de blocs de 1 KiB, découpée en 4 tranches. Au sein
+/// d'une tranche, les segments des différentes lanes ne dépendent pas les uns des autres : ils
+/// sont remplis en parallèle sur [`Argon2::threads`] threads, avec un résultat identique
+/// quel que soit leur nombre.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::kdf::argon2::{Algorithm, Argon2};
+///
+/// let hasher = Argon2::new(Algorithm::Argon2id)
+///     .memory_cost(256)
+///     .time_cost(2)
+///     .parallelism(2);
+///
+/// let tag = hasher.hash(b"password", b"somesalt").unwrap();
+/// assert!(hasher.verify(b"password", b"somesalt", &tag).unwrap());
+/// ```
+///
+/// # Références
+///
+/// - RFC 9106: Argon2 Memory-Hard Function for Password Hashing and Proof-of-Work Applications
+use crate::hash::blake2::blake2b::Blake2b;
+use crate::primitives::ct::ct_eq;
+
+use std::fmt::{Display, Formatter, Result};
+use std::thread;
+
+/// Argon2 version implemented by this module (1.3).
+pub const ARGON2_VERSION: u32 = 0x13;
+
+/// Size of a memory block in bytes.
+pub const ARGON2_BLOCK_LEN: usize = 1024;
+
+/// Minimum salt length in bytes.
+pub const ARGON2_MIN_SALT_LEN: usize = 8;
+
+/// Minimum tag length in bytes.
+pub const ARGON2_MIN_OUT_LEN: usize = 4;
+
+/// Maximum number of lanes (`2^24 - 1`).
+pub const ARGON2_MAX_PARALLELISM: u32 = 0x00FF_FFFF;
+
+/// Number of slices each lane is split into.
+const SYNC_POINTS: usize = 4;
+
+/// Number of 64-bit words in a memory block.
+const BLOCK_WORDS: usize = ARGON2_BLOCK_LEN / 8;
+
+/// Errors returned when hashing with invalid parameters.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Argon2Error {
+    /// The tag length is below 4 bytes or above `2^32 - 1`.
+    InvalidOutputLength(usize),
+    /// The salt is shorter than 8 bytes.
+    SaltTooShort(usize),
+    /// The number of passes is zero.
+    InvalidTimeCost(u32),
+    /// The number of lanes is zero or above `2^24 - 1`.
+    InvalidParallelism(u32),
+    /// The memory cost (in KiB) is below `8 × p`.
+    MemoryTooLow { memory: u32, min: u32 },
+    /// An input (password, salt, secret or associated data) is longer than `2^32 - 1` bytes.
+    InputTooLong(usize),
+    /// The memory matrix (in bytes) could not be allocated.
+    AllocationFailed(usize),
+}
+
+impl Display for Argon2Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Argon2Error::InvalidOutputLength(len) => {
+                write!(f, "invalid Argon2 output length: {len} bytes")
+            }
+            Argon2Error::SaltTooShort(len) => write!(f, "Argon2 salt too short: {len} bytes"),
+            Argon2Error::InvalidTimeCost(t) => write!(f, "invalid Argon2 time cost: {t}"),
+            Argon2Error::InvalidParallelism(p) => write!(f, "invalid Argon2 parallelism: {p}"),
+            Argon2Error::MemoryTooLow { memory, min } => {
+                write!(f, "Argon2 memory cost too low: {memory} KiB (min {min})")
+            }
+            Argon2Error::InputTooLong(len) => write!(f, "Argon2 input too long: {len} bytes"),
+            Argon2Error::AllocationFailed(bytes) => {
+                write!(f, "failed to allocate {bytes} bytes for Argon2")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Argon2Error {}
+
+/// Argon2 variant, selecting how reference blocks are chosen.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// Data-dependent addressing: fastest, but open to side-channel attacks.
+    Argon2d,
+    /// Data-independent addressing: side-channel resistant, weaker against tradeoff attacks.
+    Argon2i,
+    /// Argon2i for the first half of the first pass, Argon2d afterwards.
+    Argon2id,
+}
+
+impl Algorithm {
+    /// Returns the type identifier `y` hashed into `H0` and the address blocks.
+    fn id(self) -> u32 {
+        match self {
+            Algorithm::Argon2d => 0,
+            Algorithm::Argon2i => 1,
+            Algorithm::Argon2id => 2,
+        }
+    }
+}
+
+/// Argon2 hasher configuration.
+///
+/// Defaults follow the second recommended option of RFC 9106: 64 MiB of memory, 3 passes,
+/// 4 lanes and a 32-byte tag, with one thread per available core.
+#[derive(Clone)]
+pub struct Argon2 {
+    algorithm: Algorithm,
+    memory_cost: u32,
+    time_cost: u32,
+    parallelism: u32,
+    output_len: usize,
+    threads: usize,
+    secret: Vec<u8>,
+    associated_data: Vec<u8>,
+}
+
+impl Argon2 {
+    /// Creates a configuration for `algorithm` with the default costs.
+    pub fn new(algorithm: Algorithm) -> Self {
+        Self {
+            algorithm,
+            memory_cost: 64 * 1024,
+            time_cost: 3,
+            parallelism: 4,
+            output_len: 32,
+            threads: 0,
+            secret: Vec::new(),
+            associated_data: Vec::new(),
+        }
+    }
+
+    /// Sets the memory cost `m` in KiB (at least `8 × p`).
+    ///
+    /// The value is rounded down to a multiple of `4 × p` blocks.
+    pub fn memory_cost(mut self, kib: u32) -> Self {
+        self.memory_cost = kib;
+        self
+    }
+
+    /// Sets the time cost `t`, the number of passes over memory (at least 1).
+    pub fn time_cost(mut self, passes: u32) -> Self {
+        self.time_cost = passes;
+        self
+    }
+
+    /// Sets the degree of parallelism `p`, the number of lanes (1 to `2^24 - 1`).
+    ///
+    /// This is part of the hash definition; the number of threads is set separately.
+    pub fn parallelism(mut self, lanes: u32) -> Self {
+        self.parallelism = lanes;
+        self
+    }
+
+    /// Sets the tag length `T` in bytes (at least 4).
+    pub fn output_len(mut self, len: usize) -> Self {
+        self.output_len = len;
+        self
+    }
+
+    /// Sets the number of worker threads; `0` uses one per available core.
+    ///
+    /// At most `p` threads are used and the tag does not depend on this value.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Sets the secret value `K` (a pepper kept outside the password database).
+    pub fn secret(mut self, secret: &[u8]) -> Self {
+        self.secret = secret.to_vec();
+        self
+    }
+
+    /// Sets the associated data `X`.
+    pub fn associated_data(mut self, data: &[u8]) -> Self {
+        self.associated_data = data.to_vec();
+        self
+    }
+
+    /// Computes the Argon2 tag of `password` with `salt`.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` - The password `P`
+    /// * `salt` - The salt `S`, at least 8 bytes (16 recommended)
+    ///
+    /// # Returns
+    ///
+    /// The `output_len`-byte tag, or an [`Argon2Error`] for invalid parameters
+    pub fn hash(&self, password: &[u8], salt: &[u8]) -> std::result::Result<Vec<u8>, Argon2Error> {
+        // Reject an invalid output length before allocating it
+        self.validate(password, salt, self.output_len)?;
+
+        let mut out = vec![0u8; self.output_len];
+        self.hash_into(password, salt, &mut out)?;
+
+        Ok(out)
+    }
+
+    /// Computes the Argon2 tag into `out`, whose length overrides `output_len`.
+    pub fn hash_into(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+        out: &mut [u8],
+    ) -> std::result::Result<(), Argon2Error> {
+        self.validate(password, salt, out.len())?;
+
+        let context = Context::new(self);
+        let h0 = self.initial_hash(password, salt, out.len());
+        let mut memory = try_alloc(context.lanes * context.lane_len)?;
+
+        let threads = match self.threads {
+            0 => thread::available_parallelism().map_or(1, |n| n.get()),
+            n => n,
+        }
+        .min(context.lanes);
+
+        for pass in 0..context.passes {
+            for slice in 0..SYNC_POINTS {
+                context.fill_slice(&mut memory, &h0, pass, slice, threads);
+            }
+        }
+
+        // XOR of the last block of every lane
+        let mut last = Block::ZERO;
+        for lane in 0..context.lanes {
+            last.xor_with(&memory[context.offset(lane, context.lane_len - 1)]);
+        }
+
+        blake2b_long(out, &[&last.to_bytes()]);
+
+        Ok(())
+    }
+
+    /// Recomputes the tag for `password` and compares it to `tag` in constant time.
+    ///
+    /// The tag length is taken from `tag` rather than from `output_len`.
+    pub fn verify(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+        tag: &[u8],
+    ) -> std::result::Result<bool, Argon2Error> {
+        let mut expected = vec![0u8; tag.len()];
+        self.hash_into(password, salt, &mut expected)?;
+
+        Ok(ct_eq(&expected, tag))
+    }
+
+    /// Checks every parameter against the ranges of RFC 9106.
+    fn validate(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+        out_len: usize,
+    ) -> std::result::Result<(), Argon2Error> {
+        if out_len < ARGON2_MIN_OUT_LEN || out_len > u32::MAX as usize {
+            return Err(Argon2Error::InvalidOutputLength(out_len));
+        }
+        if salt.len() < ARGON2_MIN_SALT_LEN {
+            return Err(Argon2Error::SaltTooShort(salt.len()));
+        }
+        if self.time_cost == 0 {
+            return Err(Argon2Error::InvalidTimeCost(self.time_cost));
+        }
+        if self.parallelism == 0 || self.parallelism > ARGON2_MAX_PARALLELISM {
+            return Err(Argon2Error::InvalidParallelism(self.parallelism));
+        }
+        if self.memory_cost < 8 * self.parallelism {
+            return Err(Argon2Error::MemoryTooLow {
+                memory: self.memory_cost,
+                min: 8 * self.parallelism,
+            });
+        }
+
+        for input in [password, salt, &self.secret, &self.associated_data] {
+            if input.len() > u32::MAX as usize {
+                return Err(Argon2Error::InputTooLong(input.len()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Computes the 64-byte pre-hash `H0` of all parameters and inputs.
+    fn initial_hash(&self, password: &[u8], salt: &[u8], out_len: usize) -> [u8; 64] {
+        let mut hasher = Blake2b::new(64).expect("64 is a valid BLAKE2b length");
+
+        for value in [
+            self.parallelism,
+            out_len as u32,
+            self.memory_cost,
+            self.time_cost,
+            ARGON2_VERSION,
+            self.algorithm.id(),
+        ] {
+            hasher.update(&value.to_le_bytes());
+        }
+
+        for input in [password, salt, &self.secret, &self.associated_data] {
+            hasher.update(&(input.len() as u32).to_le_bytes());
+            hasher.update(input);
+        }
+
+        let mut h0 = [0u8; 64];
+        h0.copy_from_slice(&hasher.finalize());
+
+        h0
+    }
+}
+
+impl Default for Argon2 {
+    fn default() -> Self {
+        Self::new(Algorithm::Argon2id)
+    }
+}
+
+/// Hashes `password` with Argon2id and the default costs of [`Argon2`].
+///
+/// # Arguments
+///
+/// * `password` - The password
+/// * `salt` - The salt, at least 8 bytes (16 recommended)
+///
+/// # Returns
+///
+/// The 32-byte tag, or an [`Argon2Error`] if the salt is too short
+pub fn argon2id(password: &[u8], salt: &[u8]) -> std::result::Result<Vec<u8>, Argon2Error> {
+    Argon2::new(Algorithm::Argon2id).hash(password, salt)
+}
+
+/// One 1 KiB memory block, as 128 little-endian 64-bit words.
+#[derive(Copy, Clone)]
+struct Block([u64; BLOCK_WORDS]);
+
+impl Block {
+    const ZERO: Block = Block([0; BLOCK_WORDS]);
+
+    fn from_bytes(bytes: &[u8; ARGON2_BLOCK_LEN]) -> Self {
+        let mut block = Self::ZERO;
+        for (w, chunk) in block.0.iter_mut().zip(bytes.as_chunks::<8>().0) {
+            *w = u64::from_le_bytes(*chunk);
+        }
+
+        block
+    }
+
+    fn to_bytes(self) -> [u8; ARGON2_BLOCK_LEN] {
+        let mut bytes = [0u8; ARGON2_BLOCK_LEN];
+        for (chunk, w) in bytes.as_chunks_mut::<8>().0.iter_mut().zip(self.0) {
+            *chunk = w.to_le_bytes();
+        }
+
+        bytes
+    }
+
+    fn xor_with(&mut self, other: &Block) {
+        for (a, b) in self.0.iter_mut().zip(&other.0) {
+            *a ^= b;
+        }
+    }
+}
+
+/// The BLAKE2b round function with the extra multiplication of Argon2.
+#[inline(always)]
+fn gb(v: &mut [u64; BLOCK_WORDS], a: usize, b: usize, c: usize, d: usize) {
+    #[inline(always)]
+    fn fblamka(x: u64, y: u64) -> u64 {
+        let m = (x & 0xFFFF_FFFF) * (y & 0xFFFF_FFFF);
+        x.wrapping_add(y).wrapping_add(m.wrapping_mul(2))
+    }
+
+    v[a] = fblamka(v[a], v[b]);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = fblamka(v[c], v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = fblamka(v[a], v[b]);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = fblamka(v[c], v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+/// The permutation `P` over sixteen words given by their indices.
+#[inline(always)]
+fn permute(v: &mut [u64; BLOCK_WORDS], i: [usize; 16]) {
+    gb(v, i[0], i[4], i[8], i[12]);
+    gb(v, i[1], i[5], i[9], i[13]);
+    gb(v, i[2], i[6], i[10], i[14]);
+    gb(v, i[3], i[7], i[11], i[15]);
+    gb(v, i[0], i[5], i[10], i[15]);
+    gb(v, i[1], i[6], i[11], i[12]);
+    gb(v, i[2], i[7], i[8], i[13]);
+    gb(v, i[3], i[4], i[9], i[14]);
+}
+
+/// The compression function `G(X, Y)`.
+///
+/// `R = X ^ Y` is viewed as an 8×8 matrix of 16-byte registers; `P` is applied to every row,
+/// then every column, and the result is XORed with `R`.
+fn compress(x: &Block, y: &Block) -> Block {
+    let mut r = *x;
+    r.xor_with(y);
+
+    let mut q = r;
+
+    for row in 0..8 {
+        let base = row * 16;
+        permute(&mut q.0, std::array::from_fn(|k| base + k));
+    }
+
+    for column in 0..8 {
+        let base = column * 2;
+        permute(
+            &mut q.0,
+            std::array::from_fn(|k| base + (k / 2) * 16 + k % 2),
+        );
+    }
+
+    q.xor_with(&r);
+    q
+}
+
+/// The variable-length hash `H'` built on BLAKE2b.
+///
+/// Hashes `LE32(T) || inputs` into `out`, chaining 64-byte BLAKE2b digests and keeping
+/// the first half of each when `T` exceeds 64 bytes.
+fn blake2b_long(out: &mut [u8], inputs: &[&[u8]]) {
+    let hash = |len: usize, parts: &[&[u8]]| {
+        let mut hasher = Blake2b::new(len).expect("length in 1..=64");
+        for part in parts {
+            hasher.update(part);
+        }
+        hasher.finalize()
+    };
+
+    let len = (out.len() as u32).to_le_bytes();
+    let mut parts = vec![&len[..]];
+    parts.extend_from_slice(inputs);
+
+    if out.len() <= 64 {
+        out.copy_from_slice(&hash(out.len(), &parts));
+        return;
+    }
+
+    let mut v = hash(64, &parts);
+    out[..32].copy_from_slice(&v[..32]);
+    let mut pos = 32;
+
+    while out.len() - pos > 64 {
+        v = hash(64, &[&v]);
+        out[pos..pos + 32].copy_from_slice(&v[..32]);
+        pos += 32;
+    }
+
+    let rest = out.len() - pos;
+    out[pos..].copy_from_slice(&hash(rest, &[&v]));
+}
+
+/// Allocates the zeroed memory matrix, reporting failure instead of aborting.
+fn try_alloc(blocks: usize) -> std::result::Result<Vec<Block>, Argon2Error> {
+    let mut memory = Vec::new();
+    memory
+        .try_reserve_exact(blocks)
+        .map_err(|_| Argon2Error::AllocationFailed(blocks * ARGON2_BLOCK_LEN))?;
+    memory.resize(blocks, Block::ZERO);
+
+    Ok(memory)
+}
+
+/// Derived sizes shared by every segment.
+///
+/// Memory is stored slice by slice (`[slice][lane][block]`) rather than lane by lane, so the
+/// segments being filled form one contiguous region that can be split between threads while
+/// the other slices stay readable.
+struct Context {
+    algorithm: Algorithm,
+    lanes: usize,
+    lane_len: usize,
+    segment_len: usize,
+    passes: u32,
+}
+
+impl Context {
+    fn new(params: &Argon2) -> Self {
+        let lanes = params.parallelism as usize;
+        let segment_len = params.memory_cost as usize / (SYNC_POINTS * lanes);
+
+        Self {
+            algorithm: params.algorithm,
+            lanes,
+            lane_len: segment_len * SYNC_POINTS,
+            segment_len,
+            passes: params.time_cost,
+        }
+    }
+
+    /// Number of blocks in one slice across all lanes.
+    fn slice_len(&self) -> usize {
+        self.lanes * self.segment_len
+    }
+
+    /// Position of block `index` of `lane` in the slice-major memory.
+    fn offset(&self, lane: usize, index: usize) -> usize {
+        let slice = index / self.segment_len;
+        slice * self.slice_len() + lane * self.segment_len + index % self.segment_len
+    }
+
+    /// Fills the segments of every lane in `slice`, spreading lanes across `threads`.
+    fn fill_slice(
+        &self,
+        memory: &mut [Block],
+        h0: &[u8; 64],
+        pass: u32,
+        slice: usize,
+        threads: usize,
+    ) {
+        let stride = self.slice_len();
+        let (before, rest) = memory.split_at_mut(slice * stride);
+        let (current, after) = rest.split_at_mut(stride);
+        let done = Done {
+            before,
+            after,
+            slice,
+            stride,
+        };
+
+        if threads <= 1 {
+            for (lane, segment) in current.chunks_mut(self.segment_len).enumerate() {
+                self.fill_segment(segment, &done, h0, pass, lane);
+            }
+
+            return;
+        }
+
+        let per_thread = self.lanes.div_ceil(threads);
+
+        thread::scope(|scope| {
+            for (run, segments) in current
+                .chunks_mut(per_thread * self.segment_len)
+                .enumerate()
+            {
+                let done = &done;
+
+                scope.spawn(move || {
+                    for (k, segment) in segments.chunks_mut(self.segment_len).enumerate() {
+                        self.fill_segment(segment, done, h0, pass, run * per_thread + k);
+                    }
+                });
+            }
+        });
+    }
+
+    /// Fills the segment of `lane` in the current slice.
+    fn fill_segment(
+        &self,
+        segment: &mut [Block],
+        done: &Done,
+        h0: &[u8; 64],
+        pass: u32,
+        lane: usize,
+    ) {
+        let slice = done.slice;
+        let data_independent = match self.algorithm {
+            Algorithm::Argon2d => false,
+            Algorithm::Argon2i => true,
+            Algorithm::Argon2id => pass == 0 && slice < SYNC_POINTS / 2,
+        };
+
+        let mut addresses = Addresses::new(self, pass, lane, slice);
+        let mut start = 0;
+
+        if pass == 0 && slice == 0 {
+            // The first two blocks of each lane come from H0
+            for (j, block) in segment.iter_mut().take(2).enumerate() {
+                let mut bytes = [0u8; ARGON2_BLOCK_LEN];
+                blake2b_long(
+                    &mut bytes,
+                    &[h0, &(j as u32).to_le_bytes(), &(lane as u32).to_le_bytes()],
+                );
+                *block = Block::from_bytes(&bytes);
+            }
+
+            start = 2;
+            if data_independent {
+                addresses.next();
+            }
+        }
+
+        for i in start..self.segment_len {
+            let index = slice * self.segment_len + i;
+
+            let previous = if i > 0 {
+                &segment[i - 1]
+            } else {
+                done.get(self, lane, (index + self.lane_len - 1) % self.lane_len)
+            };
+
+            let pseudo_rand = if data_independent {
+                if i % BLOCK_WORDS == 0 {
+                    addresses.next();
+                }
+                addresses.block.0[i % BLOCK_WORDS]
+            } else {
+                previous.0[0]
+            };
+
+            let ref_lane = if pass == 0 && slice == 0 {
+                lane
+            } else {
+                ((pseudo_rand >> 32) % self.lanes as u64) as usize
+            };
+            let ref_index =
+                self.reference_index(pass, slice, i, pseudo_rand as u32, ref_lane == lane);
+
+            let reference = if ref_lane == lane && ref_index / self.segment_len == slice {
+                &segment[ref_index % self.segment_len]
+            } else {
+                done.get(self, ref_lane, ref_index)
+            };
+
+            let block = compress(previous, reference);
+
+            if pass == 0 {
+                segment[i] = block;
+            } else {
+                segment[i].xor_with(&block);
+            }
+        }
+    }
+
+    /// Maps `J1` to the index of the reference block within its lane.
+    fn reference_index(
+        &self,
+        pass: u32,
+        slice: usize,
+        i: usize,
+        j1: u32,
+        same_lane: bool,
+    ) -> usize {
+        let seg = self.segment_len;
+        let first = (i == 0) as usize;
+
+        // Blocks that may be referenced: finished segments, plus the current one in the same lane
+        let area = match (pass, same_lane) {
+            (0, true) => slice * seg + i - 1,
+            (0, false) => slice * seg - first,
+            (_, true) => self.lane_len - seg + i - 1,
+            (_, false) => self.lane_len - seg - first,
+        };
+
+        let x = (j1 as u64 * j1 as u64) >> 32;
+        let relative = area as u64 - 1 - ((area as u64 * x) >> 32);
+
+        let start = if pass == 0 || slice == SYNC_POINTS - 1 {
+            0
+        } else {
+            (slice + 1) * seg
+        };
+
+        (start + relative as usize) % self.lane_len
+    }
+}
+
+/// Read-only view of the slices that are not being filled.
+struct Done<'a> {
+    before: &'a [Block],
+    after: &'a [Block],
+    slice: usize,
+    stride: usize,
+}
+
+impl Done<'_> {
+    /// Returns block `index` of `lane`, which must not lie in the current slice.
+    fn get(&self, context: &Context, lane: usize, index: usize) -> &Block {
+        let offset = context.offset(lane, index);
+
+        if index / context.segment_len < self.slice {
+            &self.before[offset]
+        } else {
+            &self.after[offset - (self.slice + 1) * self.stride]
+        }
+    }
+}
+
+/// Generator of pseudo-random reference positions for data-independent addressing.
+struct Addresses {
+    input: Block,
+    block: Block,
+}
+
+impl Addresses {
+    fn new(context: &Context, pass: u32, lane: usize, slice: usize) -> Self {
+        let mut input = Block::ZERO;
+        input.0[..6].copy_from_slice(&[
+            pass as u64,
+            lane as u64,
+            slice as u64,
+            (context.lanes * context.lane_len) as u64,
+            context.passes as u64,
+            context.algorithm.id() as u64,
+        ]);
+
+        Self {
+            input,
+            block: Block::ZERO,
+        }
+    }
+
+    /// Increments the counter and computes the next block `G(0, G(0, input))`.
+    fn next(&mut self) {
+        self.input.0[6] += 1;
+        let first = compress(&Block::ZERO, &self.input);
+        self.block = compress(&Block::ZERO, &first);
+    }
+}
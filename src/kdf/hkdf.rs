@@ -0,0 +1,167 @@
+/// HMAC-based extract-and-expand key derivation (HKDF).
+///
+/// Ce module fournit HKDF (RFC 5869) générique sur la fonction de hachage :
+///
+/// - [`extract`] : concentre l'entropie du matériau d'entrée (`ikm`) dans une clé
+///   pseudo-aléatoire (`PRK`) de `HashLen` octets, `PRK = HMAC(salt, ikm)`
+/// - [`expand`] : étend la `PRK` en autant d'octets que nécessaire, séparés par `info`,
+///   `T(i) = HMAC(PRK, T(i-1) || info || i)`
+/// - [`hkdf`] : les deux étapes enchaînées
+///
+/// La sortie est limitée à `255 × HashLen` octets, le compteur `i` tenant sur un octet.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::Sha256;
+/// use cryptography::kdf::hkdf::{expand, extract};
+///
+/// let prk = extract::<Sha256>(b"salt", b"input keying material");
+/// let session_key = expand::<Sha256>(prk.as_ref(), b"session", 32).unwrap();
+/// let channel_key = expand::<Sha256>(prk.as_ref(), b"channel", 32).unwrap();
+///
+/// assert_ne!(session_key, channel_key);
+/// ```
+///
+/// # Références
+///
+/// - RFC 5869: HMAC-based Extract-and-Expand Key Derivation Function (HKDF)
+use crate::hash::digest::Digest;
+use crate::mac::hmac::Hmac;
+
+use std::fmt::{Display, Formatter, Result};
+
+/// Errors returned by [`expand`] and [`hkdf`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HkdfError {
+    /// More than `255 × HashLen` bytes were requested.
+    OutputTooLong { requested: usize, max: usize },
+    /// The pseudorandom key is shorter than `HashLen` bytes.
+    PrkTooShort(usize),
+}
+
+impl Display for HkdfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            HkdfError::OutputTooLong { requested, max } => {
+                write!(f, "HKDF output too long: {requested} bytes (max {max})")
+            }
+            HkdfError::PrkTooShort(len) => write!(f, "HKDF PRK too short: {len} bytes"),
+        }
+    }
+}
+
+impl std::error::Error for HkdfError {}
+
+/// Extracts a pseudorandom key from the input keying material.
+///
+/// # Arguments
+///
+/// * `salt` - Optional non-secret random value; an empty salt acts as `HashLen` zero bytes
+/// * `ikm` - Input keying material
+///
+/// # Returns
+///
+/// The `HashLen`-byte pseudorandom key
+pub fn extract<D: Digest>(salt: &[u8], ikm: &[u8]) -> D::Output {
+    // HMAC pads short keys with zeros, so an empty salt already equals HashLen zero bytes
+    let mut mac = Hmac::<D>::new(salt);
+    mac.update(ikm);
+
+    mac.finalize()
+}
+
+/// Checks the output length and the PRK length shared by [`expand_into`] and [`expand`].
+fn validate<D: Digest>(prk: &[u8], out_len: usize) -> std::result::Result<(), HkdfError> {
+    let max = 255 * D::OUTPUT_SIZE;
+
+    if out_len > max {
+        return Err(HkdfError::OutputTooLong {
+            requested: out_len,
+            max,
+        });
+    }
+    if prk.len() < D::OUTPUT_SIZE {
+        return Err(HkdfError::PrkTooShort(prk.len()));
+    }
+
+    Ok(())
+}
+
+/// Expands a pseudorandom key into `out.len()` bytes of output keying material.
+///
+/// # Arguments
+///
+/// * `prk` - Pseudorandom key of at least `HashLen` bytes, usually from [`extract`]
+/// * `info` - Context and application specific information (may be empty)
+/// * `out` - Destination buffer, at most `255 × HashLen` bytes
+pub fn expand_into<D: Digest>(
+    prk: &[u8],
+    info: &[u8],
+    out: &mut [u8],
+) -> std::result::Result<(), HkdfError> {
+    validate::<D>(prk, out.len())?;
+
+    let keyed = Hmac::<D>::new(prk);
+    let mut previous: Option<D::Output> = None;
+
+    for (i, chunk) in out.chunks_mut(D::OUTPUT_SIZE).enumerate() {
+        let mut mac = keyed.clone();
+        if let Some(t) = &previous {
+            mac.update(t.as_ref());
+        }
+        mac.update(info);
+        // At most 255 chunks, so the counter (1-based) fits in a byte
+        mac.update(&[(i + 1) as u8]);
+
+        let t = mac.finalize();
+        chunk.copy_from_slice(&t.as_ref()[..chunk.len()]);
+        previous = Some(t);
+    }
+
+    Ok(())
+}
+
+/// Expands a pseudorandom key into `out_len` bytes of output keying material.
+///
+/// # Arguments
+///
+/// * `prk` - Pseudorandom key of at least `HashLen` bytes, usually from [`extract`]
+/// * `info` - Context and application specific information (may be empty)
+/// * `out_len` - Number of bytes to produce, at most `255 × HashLen`
+///
+/// # Returns
+///
+/// The output keying material, or an [`HkdfError`]
+pub fn expand<D: Digest>(
+    prk: &[u8],
+    info: &[u8],
+    out_len: usize,
+) -> std::result::Result<Vec<u8>, HkdfError> {
+    // Reject oversized requests before allocating the output
+    validate::<D>(prk, out_len)?;
+
+    let mut out = vec![0u8; out_len];
+    expand_into::<D>(prk, info, &mut out)?;
+
+    Ok(out)
+}
+
+/// Runs [`extract`] then [`expand`].
+///
+/// # Arguments
+///
+/// * `salt` - Optional non-secret random value (may be empty)
+/// * `ikm` - Input keying material
+/// * `info` - Context and application specific information (may be empty)
+/// * `out_len` - Number of bytes to produce, at most `255 × HashLen`
+pub fn hkdf<D: Digest>(
+    salt: &[u8],
+    ikm: &[u8],
+    info: &[u8],
+    out_len: usize,
+) -> std::result::Result<Vec<u8>, HkdfError> {
+    let prk = extract::<D>(salt, ikm);
+
+    expand::<D>(prk.as_ref(), info, out_len)
+}
@@ -0,0 +1,28 @@
+/// Key derivation functions.
+///
+/// Ce module regroupe les fonctions de dérivation de clés construites sur
+/// [`hash`](crate::hash) et [`mac`](crate::mac).
+///
+/// # Modules
+///
+/// - [`argon2`] : Argon2d, Argon2i et Argon2id (RFC 9106), recommandé pour le stockage de
+///   mots de passe
+/// - [`hkdf`] : HKDF extract-and-expand (RFC 5869), pour dériver des clés à partir de
+///   secrets déjà uniformes (échange de clés, secrets partagés)
+/// - [`pbkdf2`] : PBKDF2-HMAC (RFC 8018), dérivation à partir de mots de passe avec un
+///   nombre d'itérations configurable
+/// - [`scrypt`] : scrypt (RFC 7914), dérivation à coût mémoire configurable
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::Sha256;
+/// use cryptography::kdf::hkdf::hkdf;
+///
+/// let key = hkdf::<Sha256>(b"salt", b"shared secret", b"channel 1", 32).unwrap();
+/// assert_eq!(key.len(), 32);
+/// ```
+pub mod argon2;
+pub mod hkdf;
+pub mod pbkdf2;
+pub mod scrypt;
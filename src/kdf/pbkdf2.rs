@@ -0,0 +1,223 @@
+/// Password-based key derivation (PBKDF2).
+///
+/// Ce module fournit PBKDF2 (RFC 8018, section 5.2) avec HMAC comme fonction
+/// pseudo-aléatoire, générique sur la fonction de hachage :
+///
+/// - [`pbkdf2_into`] / [`pbkdf2`] : dérivation séquentielle d'une sortie de longueur quelconque
+/// - [`pbkdf2_parallel`] : même résultat, les blocs de sortie de `HashLen` octets étant
+///   indépendants, ils sont répartis sur plusieurs threads
+/// - [`pbkdf2_hmac_sha256`] / [`pbkdf2_hmac_sha512`] : raccourcis pour les instanciations
+///   les plus courantes
+///
+/// Chaque bloc `T(i)` vaut `U1 ^ U2 ^ … ^ Uc` avec `U1 = HMAC(P, S || i)` et
+/// `Uj = HMAC(P, Uj-1)` : le coût est linéaire en `rounds` et en nombre de blocs. Le
+/// parallélisme ne profite donc qu'aux sorties plus longues que `HashLen`.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::Sha256;
+/// use cryptography::kdf::pbkdf2::{pbkdf2, pbkdf2_parallel};
+///
+/// let key = pbkdf2::<Sha256>(b"password", b"salt", 1000, 64).unwrap();
+/// assert_eq!(pbkdf2_parallel::<Sha256>(b"password", b"salt", 1000, 64, 2).unwrap(), key);
+/// ```
+///
+/// # Références
+///
+/// - RFC 8018: PKCS #5: Password-Based Cryptography Specification Version 2.1
+/// - RFC 6070 / RFC 7914 (section 11): PBKDF2-HMAC test vectors
+use crate::hash::digest::Digest;
+use crate::hash::{Sha256, Sha512};
+use crate::mac::hmac::Hmac;
+
+use std::fmt::{Display, Formatter, Result};
+use std::thread;
+
+/// Errors returned by the PBKDF2 functions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Pbkdf2Error {
+    /// The iteration count is zero.
+    InvalidRounds(u32),
+    /// More than `(2^32 - 1) × HashLen` output bytes were requested.
+    OutputTooLong(usize),
+}
+
+impl Display for Pbkdf2Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Pbkdf2Error::InvalidRounds(rounds) => {
+                write!(f, "invalid PBKDF2 iteration count: {rounds}")
+            }
+            Pbkdf2Error::OutputTooLong(len) => write!(f, "PBKDF2 output too long: {len} bytes"),
+        }
+    }
+}
+
+impl std::error::Error for Pbkdf2Error {}
+
+/// Computes one `HashLen`-byte output block `T(index)` into `out`, which may be shorter.
+fn block<D: Digest>(salted: &Hmac<D>, keyed: &Hmac<D>, rounds: u32, index: u32, out: &mut [u8]) {
+    let mut mac = salted.clone();
+    mac.update(&index.to_be_bytes());
+    let mut u = mac.finalize();
+
+    out.copy_from_slice(&u.as_ref()[..out.len()]);
+
+    for _ in 1..rounds {
+        let mut mac = keyed.clone();
+        mac.update(u.as_ref());
+        u = mac.finalize();
+
+        for (o, b) in out.iter_mut().zip(u.as_ref()) {
+            *o ^= b;
+        }
+    }
+}
+
+/// Computes consecutive output blocks, the first one being `T(first)`.
+fn blocks<D: Digest>(salted: &Hmac<D>, keyed: &Hmac<D>, rounds: u32, first: u32, out: &mut [u8]) {
+    for (i, chunk) in out.chunks_mut(D::OUTPUT_SIZE).enumerate() {
+        block(salted, keyed, rounds, first + i as u32, chunk);
+    }
+}
+
+/// Checks the parameters and returns the keyed and salted HMAC states.
+fn prepare<D: Digest>(
+    password: &[u8],
+    salt: &[u8],
+    rounds: u32,
+    out_len: usize,
+) -> std::result::Result<(Hmac<D>, Hmac<D>), Pbkdf2Error> {
+    if rounds == 0 {
+        return Err(Pbkdf2Error::InvalidRounds(rounds));
+    }
+    if out_len.div_ceil(D::OUTPUT_SIZE) > u32::MAX as usize {
+        return Err(Pbkdf2Error::OutputTooLong(out_len));
+    }
+
+    let keyed = Hmac::<D>::new(password);
+    let mut salted = keyed.clone();
+    salted.update(salt);
+
+    Ok((keyed, salted))
+}
+
+/// Derives `out.len()` bytes from `password` and `salt`.
+///
+/// # Arguments
+///
+/// * `password` - The password, used as the HMAC key
+/// * `salt` - The salt (should be unique per password)
+/// * `rounds` - Iteration count `c`, at least 1
+/// * `out` - Destination buffer, at most `(2^32 - 1) × HashLen` bytes
+pub fn pbkdf2_into<D: Digest>(
+    password: &[u8],
+    salt: &[u8],
+    rounds: u32,
+    out: &mut [u8],
+) -> std::result::Result<(), Pbkdf2Error> {
+    let (keyed, salted) = prepare::<D>(password, salt, rounds, out.len())?;
+
+    blocks(&salted, &keyed, rounds, 1, out);
+
+    Ok(())
+}
+
+/// Derives `out_len` bytes from `password` and `salt`.
+///
+/// # Arguments
+///
+/// * `password` - The password, used as the HMAC key
+/// * `salt` - The salt (should be unique per password)
+/// * `rounds` - Iteration count `c`, at least 1
+/// * `out_len` - Number of bytes to produce, at most `(2^32 - 1) × HashLen`
+///
+/// # Returns
+///
+/// The derived key, or a [`Pbkdf2Error`] for a zero iteration count or an over-long output
+pub fn pbkdf2<D: Digest>(
+    password: &[u8],
+    salt: &[u8],
+    rounds: u32,
+    out_len: usize,
+) -> std::result::Result<Vec<u8>, Pbkdf2Error> {
+    let (keyed, salted) = prepare::<D>(password, salt, rounds, out_len)?;
+    let mut out = vec![0u8; out_len];
+
+    blocks(&salted, &keyed, rounds, 1, &mut out);
+
+    Ok(out)
+}
+
+/// Derives `out_len` bytes, computing the output blocks on up to `threads` threads.
+///
+/// The result is identical to [`pbkdf2`]. Each thread gets a contiguous run of blocks, so
+/// at most `ceil(out_len / HashLen)` threads are used.
+///
+/// # Arguments
+///
+/// * `password` - The password, used as the HMAC key
+/// * `salt` - The salt (should be unique per password)
+/// * `rounds` - Iteration count `c`, at least 1
+/// * `out_len` - Number of bytes to produce, at most `(2^32 - 1) × HashLen`
+/// * `threads` - Number of worker threads; `0` uses one per available core
+pub fn pbkdf2_parallel<D>(
+    password: &[u8],
+    salt: &[u8],
+    rounds: u32,
+    out_len: usize,
+    threads: usize,
+) -> std::result::Result<Vec<u8>, Pbkdf2Error>
+where
+    D: Digest + Send + Sync,
+{
+    let (keyed, salted) = prepare::<D>(password, salt, rounds, out_len)?;
+    let mut out = vec![0u8; out_len];
+
+    let count = out_len.div_ceil(D::OUTPUT_SIZE);
+    let threads = match threads {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    }
+    .min(count);
+
+    if threads <= 1 {
+        blocks(&salted, &keyed, rounds, 1, &mut out);
+
+        return Ok(out);
+    }
+
+    let per_thread = count.div_ceil(threads);
+
+    thread::scope(|scope| {
+        for (i, run) in out.chunks_mut(per_thread * D::OUTPUT_SIZE).enumerate() {
+            let (keyed, salted) = (&keyed, &salted);
+            let first = (i * per_thread + 1) as u32;
+
+            scope.spawn(move || blocks(salted, keyed, rounds, first, run));
+        }
+    });
+
+    Ok(out)
+}
+
+/// Computes PBKDF2-HMAC-SHA256.
+pub fn pbkdf2_hmac_sha256(
+    password: &[u8],
+    salt: &[u8],
+    rounds: u32,
+    out_len: usize,
+) -> std::result::Result<Vec<u8>, Pbkdf2Error> {
+    pbkdf2::<Sha256>(password, salt, rounds, out_len)
+}
+
+/// Computes PBKDF2-HMAC-SHA512.
+pub fn pbkdf2_hmac_sha512(
+    password: &[u8],
+    salt: &[u8],
+    rounds: u32,
+    out_len: usize,
+) -> std::result::Result<Vec<u8>, Pbkdf2Error> {
+    pbkdf2::<Sha512>(password, salt, rounds, out_len)
+}
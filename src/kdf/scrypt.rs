@@ -0,0 +1,356 @@
+/// Memory-hard password-based key derivation (scrypt).
+///
+/// Ce module fournit scrypt (RFC 7914), une fonction de dérivation de clés dont le coût
+/// est à la fois en temps et en mémoire, ce qui rend les attaques matérielles massivement
+/// parallèles (GPU, ASIC) bien plus coûteuses qu'avec [`pbkdf2`](crate::kdf::pbkdf2) :
+///
+/// - [`Params`] : paramètres `N` (coût CPU/mémoire), `r` (taille de bloc) et `p`
+///   (parallélisme), validés à la construction
+/// - [`scrypt`] : dérivation séquentielle
+/// - [`scrypt_parallel`] : même résultat, les `p` lanes indépendantes étant réparties sur
+///   plusieurs threads (chaque thread alloue sa propre table de `128 × r × N` octets)
+///
+/// L'algorithme est une composition de PBKDF2-HMAC-SHA256, BlockMix (construit sur le noyau
+/// Salsa20/8) et ROMix, qui remplit puis relit dans un ordre dépendant des données une
+/// table de `N` blocs.
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::kdf::scrypt::{Params, scrypt};
+///
+/// let params = Params::new(1024, 8, 1).unwrap();
+/// let key = scrypt(b"password", b"salt", &params, 32).unwrap();
+/// assert_eq!(key.len(), 32);
+/// ```
+///
+/// # Références
+///
+/// - RFC 7914: The scrypt Password-Based Key Derivation Function
+use crate::hash::Sha256;
+use crate::kdf::pbkdf2::{pbkdf2, pbkdf2_into};
+
+use std::fmt::{Display, Formatter, Result};
+use std::thread;
+
+/// Errors returned when building [`Params`] or deriving a key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ScryptError {
+    /// `N` is not a power of two greater than 1, or not below `2^(16 × r)`.
+    InvalidN(u64),
+    /// `r` is zero.
+    InvalidR(u32),
+    /// `p` is zero.
+    InvalidP(u32),
+    /// `r × p` is not below `2^30`.
+    RpTooLarge { r: u32, p: u32 },
+    /// `128 × r × N` or `128 × r × p` does not fit in the address space.
+    MemoryOverflow,
+    /// More than `(2^32 - 1) × 32` output bytes were requested.
+    OutputTooLong(usize),
+    /// The working memory (in bytes) could not be allocated.
+    AllocationFailed(usize),
+}
+
+impl Display for ScryptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ScryptError::InvalidN(n) => {
+                write!(f, "invalid scrypt cost N = {n}: must be a power of two > 1")
+            }
+            ScryptError::InvalidR(r) => write!(f, "invalid scrypt block size r = {r}"),
+            ScryptError::InvalidP(p) => write!(f, "invalid scrypt parallelism p = {p}"),
+            ScryptError::RpTooLarge { r, p } => {
+                write!(f, "scrypt r × p too large: {r} × {p} (must be < 2^30)")
+            }
+            ScryptError::MemoryOverflow => f.write_str("scrypt memory requirement overflows"),
+            ScryptError::OutputTooLong(len) => write!(f, "scrypt output too long: {len} bytes"),
+            ScryptError::AllocationFailed(bytes) => {
+                write!(f, "failed to allocate {bytes} bytes for scrypt")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScryptError {}
+
+/// Validated scrypt parameters.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Params {
+    n: u64,
+    r: u32,
+    p: u32,
+}
+
+impl Params {
+    /// Validates and builds a parameter set.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - CPU/memory cost, a power of two greater than 1
+    /// * `r` - Block size factor (blocks are `128 × r` bytes)
+    /// * `p` - Parallelization factor, the number of independent lanes
+    ///
+    /// # Returns
+    ///
+    /// The parameters, or a [`ScryptError`] if they are out of range or would overflow memory
+    pub fn new(n: u64, r: u32, p: u32) -> std::result::Result<Self, ScryptError> {
+        if n < 2 || !n.is_power_of_two() {
+            return Err(ScryptError::InvalidN(n));
+        }
+        if r == 0 {
+            return Err(ScryptError::InvalidR(r));
+        }
+        if p == 0 {
+            return Err(ScryptError::InvalidP(p));
+        }
+        if r as u64 * p as u64 >= 1 << 30 {
+            return Err(ScryptError::RpTooLarge { r, p });
+        }
+        // N < 2^(128 × r / 8); always holds for r >= 4 since N fits in 64 bits
+        if r < 4 && n >= 1 << (16 * r) {
+            return Err(ScryptError::InvalidN(n));
+        }
+
+        let block = 128 * r as u64;
+        let fits = |bytes: Option<u64>| bytes.is_some_and(|b| b <= isize::MAX as u64);
+
+        if !fits(block.checked_mul(n)) || !fits(block.checked_mul(p as u64)) {
+            return Err(ScryptError::MemoryOverflow);
+        }
+
+        Ok(Self { n, r, p })
+    }
+
+    /// Returns the cost parameter `N`.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the block size factor `r`.
+    pub fn r(&self) -> u32 {
+        self.r
+    }
+
+    /// Returns the parallelization factor `p`.
+    pub fn p(&self) -> u32 {
+        self.p
+    }
+
+    /// Returns the size in bytes of the ROMix table used by one lane (`128 × r × N`).
+    pub fn memory_per_lane(&self) -> usize {
+        128 * self.r as usize * self.n as usize
+    }
+
+    /// Number of 32-bit words in one `128 × r` byte block.
+    fn block_words(&self) -> usize {
+        32 * self.r as usize
+    }
+}
+
+/// Applies the Salsa20/8 core to a 64-byte block in place.
+fn salsa20_8(block: &mut [u32; 16]) {
+    let mut x = *block;
+
+    macro_rules! quarter {
+        ($a:expr, $b:expr, $c:expr, $d:expr) => {
+            x[$b] ^= x[$a].wrapping_add(x[$d]).rotate_left(7);
+            x[$c] ^= x[$b].wrapping_add(x[$a]).rotate_left(9);
+            x[$d] ^= x[$c].wrapping_add(x[$b]).rotate_left(13);
+            x[$a] ^= x[$d].wrapping_add(x[$c]).rotate_left(18);
+        };
+    }
+
+    for _ in 0..4 {
+        // Column round
+        quarter!(0, 4, 8, 12);
+        quarter!(5, 9, 13, 1);
+        quarter!(10, 14, 2, 6);
+        quarter!(15, 3, 7, 11);
+
+        // Row round
+        quarter!(0, 1, 2, 3);
+        quarter!(5, 6, 7, 4);
+        quarter!(10, 11, 8, 9);
+        quarter!(15, 12, 13, 14);
+    }
+
+    for (b, v) in block.iter_mut().zip(x) {
+        *b = b.wrapping_add(v);
+    }
+}
+
+/// BlockMix with Salsa20/8: mixes the `2r` 64-byte sub-blocks of `input` into `output`.
+///
+/// Even-indexed results go to the first half of `output`, odd-indexed ones to the second.
+fn block_mix(input: &[u32], output: &mut [u32]) {
+    let (sub_blocks, _) = input.as_chunks::<16>();
+    let r = sub_blocks.len() / 2;
+
+    let mut x = sub_blocks[2 * r - 1];
+
+    for (i, sub_block) in sub_blocks.iter().enumerate() {
+        for (x, b) in x.iter_mut().zip(sub_block) {
+            *x ^= b;
+        }
+        salsa20_8(&mut x);
+
+        let dest = (i / 2 + (i % 2) * r) * 16;
+        output[dest..dest + 16].copy_from_slice(&x);
+    }
+}
+
+/// Returns the first word pair of the last 64-byte sub-block, reduced modulo `n`.
+fn integerify(block: &[u32], n: u64) -> usize {
+    let last = block.len() - 16;
+    let value = block[last] as u64 | (block[last + 1] as u64) << 32;
+
+    (value & (n - 1)) as usize
+}
+
+/// ROMix: the sequential memory-hard mixing of one `128 × r` byte lane.
+///
+/// `table` holds `N` blocks and `scratch` one block; both are overwritten.
+fn ro_mix(lane: &mut [u8], params: &Params, table: &mut [u32], scratch: &mut [u32]) {
+    let words = params.block_words();
+    let mut x: Vec<u32> = lane
+        .as_chunks::<4>()
+        .0
+        .iter()
+        .map(|w| u32::from_le_bytes(*w))
+        .collect();
+
+    for entry in table.chunks_exact_mut(words) {
+        entry.copy_from_slice(&x);
+        block_mix(&x, scratch);
+        x.copy_from_slice(scratch);
+    }
+
+    for _ in 0..params.n {
+        let j = integerify(&x, params.n);
+
+        for (x, v) in x.iter_mut().zip(&table[j * words..(j + 1) * words]) {
+            *x ^= v;
+        }
+        block_mix(&x, scratch);
+        x.copy_from_slice(scratch);
+    }
+
+    for (bytes, w) in lane.as_chunks_mut::<4>().0.iter_mut().zip(x) {
+        *bytes = w.to_le_bytes();
+    }
+}
+
+/// Allocates a zeroed word buffer, reporting failure instead of aborting.
+fn try_alloc(words: usize) -> std::result::Result<Vec<u32>, ScryptError> {
+    let mut buffer = Vec::new();
+    buffer
+        .try_reserve_exact(words)
+        .map_err(|_| ScryptError::AllocationFailed(words * 4))?;
+    buffer.resize(words, 0);
+
+    Ok(buffer)
+}
+
+/// Runs ROMix over a run of consecutive lanes, reusing one table.
+fn mix_lanes(lanes: &mut [u8], params: &Params) -> std::result::Result<(), ScryptError> {
+    let words = params.block_words();
+    let mut table = try_alloc(words * params.n as usize)?;
+    let mut scratch = vec![0u32; words];
+
+    for lane in lanes.chunks_exact_mut(words * 4) {
+        ro_mix(lane, params, &mut table, &mut scratch);
+    }
+
+    Ok(())
+}
+
+/// Shared driver: expands the password, mixes the lanes and compresses the result.
+fn derive(
+    password: &[u8],
+    salt: &[u8],
+    params: &Params,
+    out_len: usize,
+    threads: usize,
+) -> std::result::Result<Vec<u8>, ScryptError> {
+    // Checked up front so an over-long output fails before the expensive mixing
+    if out_len.div_ceil(32) > u32::MAX as usize {
+        return Err(ScryptError::OutputTooLong(out_len));
+    }
+
+    let lane_len = 128 * params.r as usize;
+    let mut lanes = vec![0u8; lane_len * params.p as usize];
+    // r × p < 2^30 keeps the lanes far below the PBKDF2 output limit
+    pbkdf2_into::<Sha256>(password, salt, 1, &mut lanes)
+        .map_err(|_| ScryptError::MemoryOverflow)?;
+
+    let threads = threads.min(params.p as usize);
+
+    if threads <= 1 {
+        mix_lanes(&mut lanes, params)?;
+    } else {
+        let per_thread = (params.p as usize).div_ceil(threads);
+
+        thread::scope(|scope| {
+            let workers: Vec<_> = lanes
+                .chunks_mut(per_thread * lane_len)
+                .map(|run| scope.spawn(move || mix_lanes(run, params)))
+                .collect();
+
+            workers
+                .into_iter()
+                .try_for_each(|worker| worker.join().expect("scrypt worker panicked"))
+        })?;
+    }
+
+    pbkdf2::<Sha256>(password, &lanes, 1, out_len).map_err(|_| ScryptError::OutputTooLong(out_len))
+}
+
+/// Derives `out_len` bytes from `password` and `salt` with scrypt.
+///
+/// # Arguments
+///
+/// * `password` - The password
+/// * `salt` - The salt (should be unique per password)
+/// * `params` - Validated cost parameters
+/// * `out_len` - Number of bytes to produce
+///
+/// # Returns
+///
+/// The derived key, or a [`ScryptError`] if the output is too long or the table cannot be allocated
+pub fn scrypt(
+    password: &[u8],
+    salt: &[u8],
+    params: &Params,
+    out_len: usize,
+) -> std::result::Result<Vec<u8>, ScryptError> {
+    derive(password, salt, params, out_len, 1)
+}
+
+/// Derives `out_len` bytes, mixing the `p` lanes on up to `threads` threads.
+///
+/// The result is identical to [`scrypt`]. Each thread allocates its own
+/// [`memory_per_lane`](Params::memory_per_lane) table, so peak memory grows with the
+/// number of threads actually used (at most `p`).
+///
+/// # Arguments
+///
+/// * `password` - The password
+/// * `salt` - The salt (should be unique per password)
+/// * `params` - Validated cost parameters
+/// * `out_len` - Number of bytes to produce
+/// * `threads` - Number of worker threads; `0` uses one per available core
+pub fn scrypt_parallel(
+    password: &[u8],
+    salt: &[u8],
+    params: &Params,
+    out_len: usize,
+    threads: usize,
+) -> std::result::Result<Vec<u8>, ScryptError> {
+    let threads = match threads {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    };
+
+    derive(password, salt, params, out_len, threads)
+}
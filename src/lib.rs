@@ -5,7 +5,31 @@
 ///
 /// # Features
 ///
-/// - SHA-256 hash function
+/// - SHA-256 hash function (one-shot and streaming)
+/// - SHA-224 hash function
+/// - SHA-512, SHA-384, SHA-512/224 and SHA-512/256 hash functions
+/// - SHA3-224, SHA3-256, SHA3-384 and SHA3-512 over the Keccak-f[1600] sponge
+/// - SHAKE128 and SHAKE256 extendable-output functions
+/// - cSHAKE, KMAC, TupleHash and ParallelHash (NIST SP 800-185)
+/// - Ethereum Keccak-256 with address derivation and EIP-55 checksums
+/// - BLAKE2b and BLAKE2s with key, salt and personalization
+/// - BLAKE3 with keyed hashing, key derivation, XOF and parallel updates
+/// - RIPEMD-160 and the Bitcoin `hash160` / `sha256d` compositions
+/// - `Digest` / `DynDigest` traits for code generic over the hash function
+/// - Fixed-size digest type (`Output<N>`) with hex formatting and constant-time equality
+/// - SHA-256 midstate export and import with a stable byte order
+/// - x86 SHA-NI accelerated SHA-256 compression with runtime dispatch
+/// - Multi-buffer SSE2/AVX2 SHA-256 for batches of independent messages
+/// - Fully unrolled portable SHA-256 rounds and multi-block compression
+/// - `const fn` SHA-256 for compile-time hashing
+/// - BIP-340-style tagged hashes with cached tag midstates
+/// - `std::io` integration: `Write` for SHA-256, reader hashing and tee adapters
+/// - Versioned multi-threaded tree-hash mode over SHA-256
+/// - HMAC over any `Digest`, with constant-time tag verification
+/// - HKDF (RFC 5869) key derivation generic over the hash function
+/// - PBKDF2-HMAC password-based key derivation, sequential or multi-threaded
+/// - scrypt memory-hard key derivation with optional parallelism over `p`
+/// - Argon2d, Argon2i and Argon2id password hashing (RFC 9106)
 /// - 256-bit unsigned integer (`U256`) with bitwise and shift operations
 ///
 /// # Exemple
@@ -15,6 +39,8 @@
 /// let hash = sha256(b"hello world");
 /// ```
 pub mod hash;
+pub mod kdf;
+pub mod mac;
 pub mod primitives;
 
 pub use primitives::U256;
@@ -0,0 +1,186 @@
+/// HMAC keyed-hash message authentication code.
+///
+/// Ce module fournit HMAC (RFC 2104, FIPS 198-1) au-dessus de n'importe quel [`Digest`] :
+///
+/// `HMAC(K, m) = H((K' ⊕ opad) || H((K' ⊕ ipad) || m))`
+///
+/// où `K'` est la clé complétée par des zéros jusqu'à la taille de bloc, ou d'abord hachée si
+/// elle est plus longue qu'un bloc. Les états internes et externes sont préparés à la création,
+/// puis le message peut être fourni en plusieurs fois.
+///
+/// La vérification compare les tags en temps constant via
+/// [`ct_eq`](crate::primitives::ct::ct_eq).
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::hash::Sha256;
+/// use cryptography::mac::hmac::{Hmac, hmac_sha256};
+///
+/// let mut mac = Hmac::<Sha256>::new(b"key");
+/// mac.update(b"mess");
+/// mac.update(b"age");
+///
+/// let tag = hmac_sha256(b"key", b"message");
+/// assert!(mac.verify(tag.as_ref()).is_ok());
+/// ```
+///
+/// # Références
+///
+/// - RFC 2104: HMAC: Keyed-Hashing for Message Authentication
+/// - RFC 4231: Identifiers and Test Vectors for HMAC-SHA-224, -256, -384 and -512
+use crate::hash::digest::Digest;
+use crate::hash::output::Sha256Digest;
+use crate::hash::sha256::hasher::Sha256;
+
+use crate::primitives::ct::ct_eq;
+
+use std::fmt::{Display, Formatter, Result};
+
+/// Inner padding byte.
+const IPAD: u8 = 0x36;
+
+/// Outer padding byte.
+const OPAD: u8 = 0x5C;
+
+/// Smallest truncated tag accepted by [`Hmac::verify_truncated`], in bytes (RFC 2104 §5).
+pub const MIN_TRUNCATED_LEN: usize = 10;
+
+/// Errors returned when verifying a tag.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MacError {
+    /// The tag does not match the computed MAC.
+    TagMismatch,
+    /// The tag length is not accepted (wrong size, or truncated too far).
+    InvalidTagLength(usize),
+}
+
+impl Display for MacError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            MacError::TagMismatch => f.write_str("MAC tag mismatch"),
+            MacError::InvalidTagLength(len) => write!(f, "invalid MAC tag length: {len} bytes"),
+        }
+    }
+}
+
+impl std::error::Error for MacError {}
+
+/// Streaming HMAC state over the hash function `D`.
+///
+/// Cloning a keyed instance is cheaper than creating a new one: the key blocks are already
+/// absorbed.
+#[derive(Clone)]
+pub struct Hmac<D: Digest> {
+    inner: D,
+    outer: D,
+}
+
+impl<D: Digest> Hmac<D> {
+    /// Creates an HMAC instance keyed with `key`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Secret key of any length; keys longer than `D::BLOCK_SIZE` are hashed first
+    pub fn new(key: &[u8]) -> Self {
+        let mut block = vec![0u8; D::BLOCK_SIZE];
+
+        if key.len() > D::BLOCK_SIZE {
+            let hashed = D::digest(key);
+            block[..D::OUTPUT_SIZE].copy_from_slice(hashed.as_ref());
+        } else {
+            block[..key.len()].copy_from_slice(key);
+        }
+
+        let mut inner = D::new();
+        let mut outer = D::new();
+
+        for byte in block.iter_mut() {
+            *byte ^= IPAD;
+        }
+        inner.update(&block);
+
+        // Flip from K' ^ ipad to K' ^ opad without going back through K'
+        for byte in block.iter_mut() {
+            *byte ^= IPAD ^ OPAD;
+        }
+        outer.update(&block);
+
+        block.fill(0);
+
+        Self { inner, outer }
+    }
+
+    /// Absorbs `msg` into the MAC.
+    pub fn update(&mut self, msg: &[u8]) {
+        self.inner.update(msg);
+    }
+
+    /// Consumes the instance and returns the full-length tag.
+    pub fn finalize(self) -> D::Output {
+        let inner = self.inner.finalize();
+
+        let mut outer = self.outer;
+        outer.update(inner.as_ref());
+
+        outer.finalize()
+    }
+
+    /// Consumes the instance and checks `tag` against the full-length MAC in constant time.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the tag matches, [`MacError::InvalidTagLength`] if it is not
+    /// `D::OUTPUT_SIZE` bytes long, [`MacError::TagMismatch`] otherwise
+    pub fn verify(self, tag: &[u8]) -> std::result::Result<(), MacError> {
+        if tag.len() != D::OUTPUT_SIZE {
+            return Err(MacError::InvalidTagLength(tag.len()));
+        }
+
+        self.verify_prefix(tag)
+    }
+
+    /// Consumes the instance and checks a truncated `tag` (the leftmost bytes of the MAC).
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the tag matches, [`MacError::InvalidTagLength`] if it is longer than the
+    /// MAC or shorter than half of it or [`MIN_TRUNCATED_LEN`], [`MacError::TagMismatch`]
+    /// otherwise
+    pub fn verify_truncated(self, tag: &[u8]) -> std::result::Result<(), MacError> {
+        let min_len = (D::OUTPUT_SIZE / 2).max(MIN_TRUNCATED_LEN);
+
+        if tag.len() < min_len || tag.len() > D::OUTPUT_SIZE {
+            return Err(MacError::InvalidTagLength(tag.len()));
+        }
+
+        self.verify_prefix(tag)
+    }
+
+    fn verify_prefix(self, tag: &[u8]) -> std::result::Result<(), MacError> {
+        let expected = self.finalize();
+
+        if ct_eq(&expected.as_ref()[..tag.len()], tag) {
+            Ok(())
+        } else {
+            Err(MacError::TagMismatch)
+        }
+    }
+}
+
+/// Computes HMAC-SHA256 of `msg` under `key`.
+///
+/// # Arguments
+///
+/// * `key` - Secret key of any length
+/// * `msg` - The message to authenticate
+///
+/// # Returns
+///
+/// The 32-byte tag as a [`Sha256Digest`] (constant-time equality, hex display)
+pub fn hmac_sha256(key: &[u8], msg: &[u8]) -> Sha256Digest {
+    let mut mac = Hmac::<Sha256>::new(key);
+    mac.update(msg);
+
+    mac.finalize()
+}
@@ -0,0 +1,19 @@
+/// Message authentication codes.
+///
+/// Ce module expose les MAC construits sur les fonctions de hachage de [`hash`](crate::hash).
+///
+/// # Modules
+///
+/// - [`hmac`] : HMAC (RFC 2104) générique sur [`Digest`](crate::hash::digest::Digest)
+///
+/// # Exemple
+///
+/// ```
+/// use cryptography::mac::hmac_sha256;
+///
+/// let tag = hmac_sha256(b"key", b"message");
+/// assert_eq!(tag.to_string().len(), 64);
+/// ```
+pub mod hmac;
+
+pub use hmac::{Hmac, MacError, hmac_sha256};
@@ -40,7 +40,7 @@ impl From<u8> for U256 {
 }
 
 /// View `U256` as a byte slice.
-impl AsRef<[u8]> for &U256 {
+impl AsRef<[u8]> for U256 {
     fn as_ref(&self) -> &[u8] {
         &self.0
     }
@@ -0,0 +1,38 @@
+/// Constant-time comparison helpers.
+///
+/// Ce module fournit des comparaisons dont la durée ne dépend pas du contenu comparé, à
+/// utiliser pour vérifier des digests, des tags MAC ou des clés dérivées. Seule la longueur,
+/// considérée comme publique, peut provoquer un retour anticipé.
+use std::hint::black_box;
+
+/// Compares two byte slices in time independent of their contents.
+///
+/// # Arguments
+///
+/// * `a` - First byte slice
+/// * `b` - Second byte slice
+///
+/// # Returns
+///
+/// `true` if both slices have the same length and the same bytes
+///
+/// # Example
+///
+/// ```
+/// use cryptography::primitives::ct::ct_eq;
+/// assert!(ct_eq(b"tag", b"tag"));
+/// assert!(!ct_eq(b"tag", b"tab"));
+/// ```
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        // black_box keeps the compiler from turning the loop into an early-exit comparison
+        diff |= black_box(x ^ y);
+    }
+
+    black_box(diff) == 0
+}
@@ -3,8 +3,10 @@
 /// This module expose des entiers fixes et utilitaires pour les algorithmes cryptographiques.
 /// Le type principal est [`U256`], un entier non signé 256 bits.
 ///
-/// Les helpers de conversion sont dans [`conv`], les opérateurs dans [`ops`].
+/// Les helpers de conversion sont dans [`conv`], les opérateurs dans [`ops`] et les
+/// comparaisons en temps constant dans [`ct`].
 pub mod conv;
+pub mod ct;
 pub mod ops;
 pub mod u256;
 
@@ -0,0 +1,212 @@
+use cryptography::kdf::argon2::{Algorithm, Argon2, Argon2Error, argon2id};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Parameters of the RFC 9106 section 5 test vectors.
+fn rfc9106(algorithm: Algorithm) -> Argon2 {
+    Argon2::new(algorithm)
+        .memory_cost(32)
+        .time_cost(3)
+        .parallelism(4)
+        .output_len(32)
+        .secret(&[0x03; 8])
+        .associated_data(&[0x04; 12])
+}
+
+const PASSWORD: [u8; 32] = [0x01; 32];
+const SALT: [u8; 16] = [0x02; 16];
+
+// -------------------------------------------------------
+// 1. RFC 9106 TEST VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn argon2d_rfc9106_vector() {
+    assert_eq!(
+        rfc9106(Algorithm::Argon2d).hash(&PASSWORD, &SALT).unwrap(),
+        hex("512b391b6f1162975371d30919734294f868e3be3984f3c1a13a4db9fabe4acb")
+    );
+}
+
+#[test]
+fn argon2i_rfc9106_vector() {
+    assert_eq!(
+        rfc9106(Algorithm::Argon2i).hash(&PASSWORD, &SALT).unwrap(),
+        hex("c814d9d1dc7f37aa13f0d77f2494bda1c8de6b016dd388d29952a4c4672b6ce8")
+    );
+}
+
+#[test]
+fn argon2id_rfc9106_vector() {
+    assert_eq!(
+        rfc9106(Algorithm::Argon2id).hash(&PASSWORD, &SALT).unwrap(),
+        hex("0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659")
+    );
+}
+
+// -------------------------------------------------------
+// 2. THREADS, INPUTS AND VERIFICATION
+// -------------------------------------------------------
+
+#[test]
+fn argon2_threads_do_not_change_tag() {
+    for algorithm in [Algorithm::Argon2d, Algorithm::Argon2i, Algorithm::Argon2id] {
+        let hasher = Argon2::new(algorithm)
+            .memory_cost(256)
+            .time_cost(2)
+            .parallelism(5);
+        let serial = hasher
+            .clone()
+            .threads(1)
+            .hash(b"password", b"somesalt")
+            .unwrap();
+
+        for threads in [0, 2, 3, 5, 8] {
+            assert_eq!(
+                hasher
+                    .clone()
+                    .threads(threads)
+                    .hash(b"password", b"somesalt")
+                    .unwrap(),
+                serial,
+                "{algorithm:?}, threads {threads}"
+            );
+        }
+    }
+}
+
+#[test]
+fn argon2_inputs_change_tag() {
+    let base = rfc9106(Algorithm::Argon2id);
+    let tag = base.hash(&PASSWORD, &SALT).unwrap();
+
+    assert_ne!(
+        base.clone()
+            .secret(b"other")
+            .hash(&PASSWORD, &SALT)
+            .unwrap(),
+        tag
+    );
+    assert_ne!(
+        base.clone()
+            .associated_data(b"other")
+            .hash(&PASSWORD, &SALT)
+            .unwrap(),
+        tag
+    );
+    assert_ne!(
+        base.clone().time_cost(4).hash(&PASSWORD, &SALT).unwrap(),
+        tag
+    );
+    assert_ne!(base.hash(b"password", &SALT).unwrap(), tag);
+}
+
+/// RFC 9106 inputs with tags longer than one BLAKE2b output, so the final `H'` chains
+/// several 64-byte digests. Computed with the `rust-argon2` 2.1.0 crate.
+#[test]
+fn argon2_long_output_vectors() {
+    let cases = [
+        (
+            Algorithm::Argon2d,
+            "b77af77c764297a5fd2cf38f4b1c4b3fbbc03adaf6a108526b37413ca6e583d4c0a7c9d66901b0a1cb8b67dc16eb209ddcd57e231f5c34bc500d2b79b445c973f2",
+        ),
+        (
+            Algorithm::Argon2d,
+            "1fd80d4772713414df474d0aef155e75ade3f224606adfb1000bffd591db78b4d4c00ee7f5460a33ac60c5c4ae754c916cc0f0a98d115deaa87fea61dfa7a33cccf32757f0e9e1e2358d013188e5cbca58c0bbe2b995c03b3fa9a825dfee91e6e2753eed",
+        ),
+        (
+            Algorithm::Argon2i,
+            "6c605658a1381270a7b626b3f36289ff602d78c15d5a25d72f9e25425cdb1a4c7fe4e89dff3334f702e9c8f858ea48b8c85be4feed049ac120c2591875370554de",
+        ),
+        (
+            Algorithm::Argon2i,
+            "430f1f06587febdbb28928a9779d4eeb7e2219b4e489efcfa1bad9048c82e30be9403f5d6ef703ba040adb9b0e35e5b2c46797dd6c55df30e8dd1accde258caecd7160a25511ba555dcdfeb94b70a6108ff811f8af6a7e0f6879f000b77c724cc86ca670",
+        ),
+        (
+            Algorithm::Argon2id,
+            "c7623469c08d85f17139cb2920add7bac6be215cede3fbede8e09c4d1163417f8f4caceb7af64e4b6753611af0d5a1ac6fa988183d42cb57aeaf5ab75483d8eabe",
+        ),
+        (
+            Algorithm::Argon2id,
+            "0c5e542b2ed96e38134451ffd70c9b60785084159657ca843ec8065420934fc05ccfeb0dcdbfd3b084563b0998582ba4182b72d1dae5f5b0c9e5e19c1b644536e258bfcdb483383853f2aa324d92db6b5a6eef9467ca4b38a3fc1cfb72c1ae98bb6680b2",
+        ),
+    ];
+
+    for (algorithm, expected) in cases {
+        let expected = hex(expected);
+        let tag = rfc9106(algorithm)
+            .output_len(expected.len())
+            .hash(&PASSWORD, &SALT)
+            .unwrap();
+
+        assert_eq!(tag, expected, "{algorithm:?}, T = {}", expected.len());
+    }
+}
+
+#[test]
+fn argon2_verify() {
+    let hasher = rfc9106(Algorithm::Argon2id);
+    let mut tag = hasher.hash(&PASSWORD, &SALT).unwrap();
+
+    assert_eq!(hasher.verify(&PASSWORD, &SALT, &tag), Ok(true));
+
+    tag[31] ^= 1;
+    assert_eq!(hasher.verify(&PASSWORD, &SALT, &tag), Ok(false));
+}
+
+#[test]
+fn argon2id_default_parameters() {
+    let tag = argon2id(b"password", b"somesalt").unwrap();
+
+    assert_eq!(tag.len(), 32);
+    assert_eq!(
+        tag,
+        Argon2::default().hash(b"password", b"somesalt").unwrap()
+    );
+}
+
+// -------------------------------------------------------
+// 3. PARAMETER VALIDATION
+// -------------------------------------------------------
+
+#[test]
+fn argon2_rejects_invalid_parameters() {
+    let hasher = rfc9106(Algorithm::Argon2id);
+
+    assert_eq!(
+        hasher.hash(&PASSWORD, b"short"),
+        Err(Argon2Error::SaltTooShort(5))
+    );
+    assert_eq!(
+        hasher.clone().output_len(3).hash(&PASSWORD, &SALT),
+        Err(Argon2Error::InvalidOutputLength(3))
+    );
+    assert_eq!(
+        hasher.clone().output_len(usize::MAX).hash(&PASSWORD, &SALT),
+        Err(Argon2Error::InvalidOutputLength(usize::MAX))
+    );
+    assert_eq!(
+        hasher.clone().time_cost(0).hash(&PASSWORD, &SALT),
+        Err(Argon2Error::InvalidTimeCost(0))
+    );
+    assert_eq!(
+        hasher.clone().parallelism(0).hash(&PASSWORD, &SALT),
+        Err(Argon2Error::InvalidParallelism(0))
+    );
+    assert_eq!(
+        hasher.clone().parallelism(1 << 24).hash(&PASSWORD, &SALT),
+        Err(Argon2Error::InvalidParallelism(1 << 24))
+    );
+    assert_eq!(
+        hasher.clone().memory_cost(31).hash(&PASSWORD, &SALT),
+        Err(Argon2Error::MemoryTooLow {
+            memory: 31,
+            min: 32
+        })
+    );
+}
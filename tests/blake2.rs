@@ -0,0 +1,252 @@
+use cryptography::hash::blake2::Blake2Error;
+use cryptography::hash::blake2::blake2b::{Blake2bParams, blake2b, blake2b_keyed};
+use cryptography::hash::blake2::blake2s::{Blake2sParams, blake2s, blake2s_keyed};
+use cryptography::hash::{Blake2b, Blake2b512, Blake2s, Blake2s256, blake2b512, blake2s256};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn seq(len: usize) -> Vec<u8> {
+    (0..len).map(|i| i as u8).collect()
+}
+
+// -------------------------------------------------------
+// 1. RFC 7693 APPENDIX VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn blake2b512_abc_vector() {
+    assert_eq!(
+        blake2b512(b"abc").0.to_vec(),
+        hex(
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        )
+    );
+}
+
+#[test]
+fn blake2s256_abc_vector() {
+    assert_eq!(
+        blake2s256(b"abc").0.to_vec(),
+        hex("508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982")
+    );
+}
+
+// -------------------------------------------------------
+// 2. REFERENCE KEYED KAT (key = 00.., input = 00..len-1)
+// -------------------------------------------------------
+
+#[test]
+fn blake2b_keyed_kat() {
+    let key = seq(64);
+    let cases = [
+        (
+            0,
+            "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568",
+        ),
+        (
+            1,
+            "961f6dd1e4dd30f63901690c512e78e4b45e4742ed197c3c5e45c549fd25f2e4187b0bc9fe30492b16b0d0bc4ef9b0f34c7003fac09a5ef1532e69430234cebd",
+        ),
+        (
+            63,
+            "bd965bf31e87d70327536f2a341cebc4768eca275fa05ef98f7f1b71a0351298de006fba73fe6733ed01d75801b4a928e54231b38e38c562b2e33ea1284992fa",
+        ),
+        (
+            127,
+            "76d2d819c92bce55fa8e092ab1bf9b9eab237a25267986cacf2b8ee14d214d730dc9a5aa2d7b596e86a1fd8fa0804c77402d2fcd45083688b218b1cdfa0dcbcb",
+        ),
+        (
+            128,
+            "72065ee4dd91c2d8509fa1fc28a37c7fc9fa7d5b3f8ad3d0d7a25626b57b1b44788d4caf806290425f9890a3a2a35a905ab4b37acfd0da6e4517b2525c9651e4",
+        ),
+        (
+            129,
+            "64475dfe7600d7171bea0b394e27c9b00d8e74dd1e416a79473682ad3dfdbb706631558055cfc8a40e07bd015a4540dcdea15883cbbf31412df1de1cd4152b91",
+        ),
+        (
+            255,
+            "142709d62e28fcccd0af97fad0f8465b971e82201dc51070faa0372aa43e92484be1c1e73ba10906d5d1853db6a4106e0a7bf9800d373d6dee2d46d62ef2a461",
+        ),
+    ];
+
+    for (len, expected) in cases {
+        assert_eq!(
+            blake2b_keyed(&key, &seq(len), 64).unwrap(),
+            hex(expected),
+            "len {len}"
+        );
+    }
+}
+
+#[test]
+fn blake2s_keyed_kat() {
+    let key = seq(32);
+    let cases = [
+        (
+            0,
+            "48a8997da407876b3d79c0d92325ad3b89cbb754d86ab71aee047ad345fd2c49",
+        ),
+        (
+            1,
+            "40d15fee7c328830166ac3f918650f807e7e01e177258cdc0a39b11f598066f1",
+        ),
+        (
+            63,
+            "c65382513f07460da39833cb666c5ed82e61b9e998f4b0c4287cee56c3cc9bcd",
+        ),
+        (
+            64,
+            "8975b0577fd35566d750b362b0897a26c399136df07bababbde6203ff2954ed4",
+        ),
+        (
+            65,
+            "21fe0ceb0052be7fb0f004187cacd7de67fa6eb0938d927677f2398c132317a8",
+        ),
+        (
+            255,
+            "3fb735061abc519dfe979e54c1ee5bfad0a9d858b3315bad34bde999efd724dd",
+        ),
+    ];
+
+    for (len, expected) in cases {
+        assert_eq!(
+            blake2s_keyed(&key, &seq(len), 32).unwrap(),
+            hex(expected),
+            "len {len}"
+        );
+    }
+}
+
+// -------------------------------------------------------
+// 3. PARAMETERS: OUTPUT LENGTH, SALT, PERSONALIZATION
+// -------------------------------------------------------
+
+#[test]
+fn blake2_truncated_outputs() {
+    assert_eq!(
+        blake2b(b"abc", 20).unwrap(),
+        hex("384264f676f39536840523f284921cdc68b6846b")
+    );
+    assert_eq!(blake2s(b"abc", 1).unwrap(), hex("0d"));
+}
+
+#[test]
+fn blake2b_salt_and_personal() {
+    let mut hasher = Blake2bParams::new()
+        .out_len(32)
+        .key(b"secret")
+        .salt(b"0123456789abcdef")
+        .personal(b"NebulaContentID!")
+        .build()
+        .unwrap();
+    hasher.update(b"hello");
+
+    assert_eq!(
+        hasher.finalize(),
+        hex("4145c94f7311b31cfe8cb8e95f3cc3fe3a9a9c398e04afde219401ef9ff409db")
+    );
+
+    let mut short_salt = Blake2bParams::new()
+        .out_len(1)
+        .salt(b"short")
+        .build()
+        .unwrap();
+    short_salt.update(&[]);
+    assert_eq!(short_salt.finalize(), hex("dd"));
+}
+
+#[test]
+fn blake2s_salt_and_personal() {
+    let mut hasher = Blake2sParams::new()
+        .out_len(20)
+        .key(b"secret")
+        .salt(b"saltsalt")
+        .personal(b"Nebula01")
+        .build()
+        .unwrap();
+    hasher.update(b"hello");
+
+    assert_eq!(
+        hasher.finalize(),
+        hex("c9ce0754fe6d636c6ee95ea1f2492c3c06abb4d9")
+    );
+}
+
+#[test]
+fn blake2_parameter_errors() {
+    assert_eq!(
+        Blake2b::new(0).err(),
+        Some(Blake2Error::InvalidOutputLength(0))
+    );
+    assert_eq!(
+        Blake2b::new(65).err(),
+        Some(Blake2Error::InvalidOutputLength(65))
+    );
+    assert_eq!(
+        Blake2s::new(33).err(),
+        Some(Blake2Error::InvalidOutputLength(33))
+    );
+    assert_eq!(
+        Blake2s::new_keyed(&[0u8; 33], 32).err(),
+        Some(Blake2Error::KeyTooLong(33))
+    );
+    assert_eq!(
+        Blake2bParams::new().salt(&[0u8; 17]).build().err(),
+        Some(Blake2Error::SaltTooLong(17))
+    );
+    assert_eq!(
+        Blake2sParams::new().personal(&[0u8; 9]).build().err(),
+        Some(Blake2Error::PersonalTooLong(9))
+    );
+}
+
+// -------------------------------------------------------
+// 4. STREAMING
+// -------------------------------------------------------
+
+#[test]
+fn blake2_stream_matches_one_shot() {
+    let buf = seq(700);
+
+    for step in [1, 63, 64, 65, 127, 128, 129, 699] {
+        let mut b = Blake2b::new_keyed(b"key", 48).unwrap();
+        let mut s = Blake2s::new_keyed(b"key", 28).unwrap();
+        let mut b512 = Blake2b512::new();
+        let mut s256 = Blake2s256::new();
+
+        for chunk in buf.chunks(step) {
+            b.update(chunk);
+            s.update(chunk);
+            b512.update(chunk);
+            s256.update(chunk);
+        }
+
+        assert_eq!(b.finalize(), blake2b_keyed(b"key", &buf, 48).unwrap());
+        assert_eq!(s.finalize(), blake2s_keyed(b"key", &buf, 28).unwrap());
+        assert_eq!(b512.finalize(), blake2b512(&buf));
+        assert_eq!(s256.finalize(), blake2s256(&buf));
+    }
+}
+
+#[test]
+fn blake2_reset_keeps_key() {
+    let mut hasher = Blake2s::new_keyed(&seq(32), 32).unwrap();
+    hasher.update(b"discarded");
+    hasher.reset();
+
+    assert_eq!(
+        hasher.finalize(),
+        hex("48a8997da407876b3d79c0d92325ad3b89cbb754d86ab71aee047ad345fd2c49")
+    );
+
+    let mut plain = Blake2b512::new();
+    plain.update(b"xyz");
+    plain.reset();
+    plain.update(b"abc");
+    assert_eq!(plain.finalize(), blake2b512(b"abc"));
+}
@@ -0,0 +1,386 @@
+use cryptography::hash::blake3::hasher::PARALLEL_THRESHOLD;
+use cryptography::hash::blake3::{CHUNK_LEN, derive_key, keyed_hash};
+use cryptography::hash::{Blake3, blake3};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Input pattern of the official BLAKE3 test vectors.
+fn pattern(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i % 251) as u8).collect()
+}
+
+const TEST_KEY: &[u8; 32] = b"whats the Elvish word for friend";
+const TEST_CONTEXT: &str = "BLAKE3 2019-12-27 16:29:52 test vectors context";
+
+/// Vector in the official `test_vectors.json` layout: input `pattern(len)`, 131-byte output per mode.
+struct Vector {
+    len: usize,
+    hash: &'static str,
+    keyed_hash: &'static str,
+    derive_key: &'static str,
+}
+
+/// Multi-chunk cases from the official `test_vectors.json`.
+const MULTI_CHUNK_VECTORS: [Vector; 12] = [
+    Vector {
+        len: 1024,
+        hash: "42214739f095a406f3fc83deb889744ac00df831c10daa55189b5d121c855af71cf8107265ecdaf8505b95d8fcec83a98a6a96ea5109d2c179c47a387ffbb404756f6eeae7883b446b70ebb144527c2075ab8ab204c0086bb22b7c93d465efc57f8d917f0b385c6df265e77003b85102967486ed57db5c5ca170ba441427ed9afa684e",
+        keyed_hash: "75c46f6f3d9eb4f55ecaaee480db732e6c2105546f1e675003687c31719c7ba4a78bc838c72852d4f49c864acb7adafe2478e824afe51c8919d06168414c265f298a8094b1ad813a9b8614acabac321f24ce61c5a5346eb519520d38ecc43e89b5000236df0597243e4d2493fd626730e2ba17ac4d8824d09d1a4a8f57b8227778e2de",
+        derive_key: "7356cd7720d5b66b6d0697eb3177d9f8d73a4a5c5e968896eb6a6896843027066c23b601d3ddfb391e90d5c8eccdef4ae2a264bce9e612ba15e2bc9d654af1481b2e75dbabe615974f1070bba84d56853265a34330b4766f8e75edd1f4a1650476c10802f22b64bd3919d246ba20a17558bc51c199efdec67e80a227251808d8ce5bad",
+    },
+    Vector {
+        len: 1025,
+        hash: "d00278ae47eb27b34faecf67b4fe263f82d5412916c1ffd97c8cb7fb814b8444f4c4a22b4b399155358a994e52bf255de60035742ec71bd08ac275a1b51cc6bfe332b0ef84b409108cda080e6269ed4b3e2c3f7d722aa4cdc98d16deb554e5627be8f955c98e1d5f9565a9194cad0c4285f93700062d9595adb992ae68ff12800ab67a",
+        keyed_hash: "357dc55de0c7e382c900fd6e320acc04146be01db6a8ce7210b7189bd664ea69362396b77fdc0d2634a552970843722066c3c15902ae5097e00ff53f1e116f1cd5352720113a837ab2452cafbde4d54085d9cf5d21ca613071551b25d52e69d6c81123872b6f19cd3bc1333edf0c52b94de23ba772cf82636cff4542540a7738d5b930",
+        derive_key: "effaa245f065fbf82ac186839a249707c3bddf6d3fdda22d1b95a3c970379bcb5d31013a167509e9066273ab6e2123bc835b408b067d88f96addb550d96b6852dad38e320b9d940f86db74d398c770f462118b35d2724efa13da97194491d96dd37c3c09cbef665953f2ee85ec83d88b88d11547a6f911c8217cca46defa2751e7f3ad",
+    },
+    Vector {
+        len: 2048,
+        hash: "e776b6028c7cd22a4d0ba182a8bf62205d2ef576467e838ed6f2529b85fba24a9a60bf80001410ec9eea6698cd537939fad4749edd484cb541aced55cd9bf54764d063f23f6f1e32e12958ba5cfeb1bf618ad094266d4fc3c968c2088f677454c288c67ba0dba337b9d91c7e1ba586dc9a5bc2d5e90c14f53a8863ac75655461cea8f9",
+        keyed_hash: "879cf1fa2ea0e79126cb1063617a05b6ad9d0b696d0d757cf053439f60a99dd10173b961cd574288194b23ece278c330fbb8585485e74967f31352a8183aa782b2b22f26cdcadb61eed1a5bc144b8198fbb0c13abbf8e3192c145d0a5c21633b0ef86054f42809df823389ee40811a5910dcbd1018af31c3b43aa55201ed4edaac74fe",
+        derive_key: "7b2945cb4fef70885cc5d78a87bf6f6207dd901ff239201351ffac04e1088a23e2c11a1ebffcea4d80447867b61badb1383d842d4e79645d48dd82ccba290769caa7af8eaa1bd78a2a5e6e94fbdab78d9c7b74e894879f6a515257ccf6f95056f4e25390f24f6b35ffbb74b766202569b1d797f2d4bd9d17524c720107f985f4ddc583",
+    },
+    Vector {
+        len: 2049,
+        hash: "5f4d72f40d7a5f82b15ca2b2e44b1de3c2ef86c426c95c1af0b687952256303096de31d71d74103403822a2e0bc1eb193e7aecc9643a76b7bbc0c9f9c52e8783aae98764ca468962b5c2ec92f0c74eb5448d519713e09413719431c802f948dd5d90425a4ecdadece9eb178d80f26efccae630734dff63340285adec2aed3b51073ad3",
+        keyed_hash: "9f29700902f7c86e514ddc4df1e3049f258b2472b6dd5267f61bf13983b78dd5f9a88abfefdfa1e00b418971f2b39c64ca621e8eb37fceac57fd0c8fc8e117d43b81447be22d5d8186f8f5919ba6bcc6846bd7d50726c06d245672c2ad4f61702c646499ee1173daa061ffe15bf45a631e2946d616a4c345822f1151284712f76b2b0e",
+        derive_key: "2ea477c5515cc3dd606512ee72bb3e0e758cfae7232826f35fb98ca1bcbdf27316d8e9e79081a80b046b60f6a263616f33ca464bd78d79fa18200d06c7fc9bffd808cc4755277a7d5e09da0f29ed150f6537ea9bed946227ff184cc66a72a5f8c1e4bd8b04e81cf40fe6dc4427ad5678311a61f4ffc39d195589bdbc670f63ae70f4b6",
+    },
+    Vector {
+        len: 3072,
+        hash: "b98cb0ff3623be03326b373de6b9095218513e64f1ee2edd2525c7ad1e5cffd29a3f6b0b978d6608335c09dc94ccf682f9951cdfc501bfe47b9c9189a6fc7b404d120258506341a6d802857322fbd20d3e5dae05b95c88793fa83db1cb08e7d8008d1599b6209d78336e24839724c191b2a52a80448306e0daa84a3fdb566661a37e11",
+        keyed_hash: "044a0e7b172a312dc02a4c9a818c036ffa2776368d7f528268d2e6b5df19177022f302d0529e4174cc507c463671217975e81dab02b8fdeb0d7ccc7568dd22574c783a76be215441b32e91b9a904be8ea81f7a0afd14bad8ee7c8efc305ace5d3dd61b996febe8da4f56ca0919359a7533216e2999fc87ff7d8f176fbecb3d6f34278b",
+        derive_key: "050df97f8c2ead654d9bb3ab8c9178edcd902a32f8495949feadcc1e0480c46b3604131bbd6e3ba573b6dd682fa0a63e5b165d39fc43a625d00207607a2bfeb65ff1d29292152e26b298868e3b87be95d6458f6f2ce6118437b632415abe6ad522874bcd79e4030a5e7bad2efa90a7a7c67e93f0a18fb28369d0a9329ab5c24134ccb0",
+    },
+    Vector {
+        len: 3073,
+        hash: "7124b49501012f81cc7f11ca069ec9226cecb8a2c850cfe644e327d22d3e1cd39a27ae3b79d68d89da9bf25bc27139ae65a324918a5f9b7828181e52cf373c84f35b639b7fccbb985b6f2fa56aea0c18f531203497b8bbd3a07ceb5926f1cab74d14bd66486d9a91eba99059a98bd1cd25876b2af5a76c3e9eed554ed72ea952b603bf",
+        keyed_hash: "68dede9bef00ba89e43f31a6825f4cf433389fedae75c04ee9f0cf16a427c95a96d6da3fe985054d3478865be9a092250839a697bbda74e279e8a9e69f0025e4cfddd6cfb434b1cd9543aaf97c635d1b451a4386041e4bb100f5e45407cbbc24fa53ea2de3536ccb329e4eb9466ec37093a42cf62b82903c696a93a50b702c80f3c3c5",
+        derive_key: "72613c9ec9ff7e40f8f5c173784c532ad852e827dba2bf85b2ab4b76f7079081576288e552647a9d86481c2cae75c2dd4e7c5195fb9ada1ef50e9c5098c249d743929191441301c69e1f48505a4305ec1778450ee48b8e69dc23a25960fe33070ea549119599760a8a2d28aeca06b8c5e9ba58bc19e11fe57b6ee98aa44b2a8e6b14a5",
+    },
+    Vector {
+        len: 4096,
+        hash: "015094013f57a5277b59d8475c0501042c0b642e531b0a1c8f58d2163229e9690289e9409ddb1b99768eafe1623da896faf7e1114bebeadc1be30829b6f8af707d85c298f4f0ff4d9438aef948335612ae921e76d411c3a9111df62d27eaf871959ae0062b5492a0feb98ef3ed4af277f5395172dbe5c311918ea0074ce0036454f620",
+        keyed_hash: "befc660aea2f1718884cd8deb9902811d332f4fc4a38cf7c7300d597a081bfc0bbb64a36edb564e01e4b4aaf3b060092a6b838bea44afebd2deb8298fa562b7b597c757b9df4c911c3ca462e2ac89e9a787357aaf74c3b56d5c07bc93ce899568a3eb17d9250c20f6c5f6c1e792ec9a2dcb715398d5a6ec6d5c54f586a00403a1af1de",
+        derive_key: "1e0d7f3db8c414c97c6307cbda6cd27ac3b030949da8e23be1a1a924ad2f25b9d78038f7b198596c6cc4a9ccf93223c08722d684f240ff6569075ed81591fd93f9fff1110b3a75bc67e426012e5588959cc5a4c192173a03c00731cf84544f65a2fb9378989f72e9694a6a394a8a30997c2e67f95a504e631cd2c5f55246024761b245",
+    },
+    Vector {
+        len: 4097,
+        hash: "9b4052b38f1c5fc8b1f9ff7ac7b27cd242487b3d890d15c96a1c25b8aa0fb99505f91b0b5600a11251652eacfa9497b31cd3c409ce2e45cfe6c0a016967316c426bd26f619eab5d70af9a418b845c608840390f361630bd497b1ab44019316357c61dbe091ce72fc16dc340ac3d6e009e050b3adac4b5b2c92e722cffdc46501531956",
+        keyed_hash: "00df940cd36bb9fa7cbbc3556744e0dbc8191401afe70520ba292ee3ca80abbc606db4976cfdd266ae0abf667d9481831ff12e0caa268e7d3e57260c0824115a54ce595ccc897786d9dcbf495599cfd90157186a46ec800a6763f1c59e36197e9939e900809f7077c102f888caaf864b253bc41eea812656d46742e4ea42769f89b83f",
+        derive_key: "aca51029626b55fda7117b42a7c211f8c6e9ba4fe5b7a8ca922f34299500ead8a897f66a400fed9198fd61dd2d58d382458e64e100128075fc54b860934e8de2e84170734b06e1d212a117100820dbc48292d148afa50567b8b84b1ec336ae10d40c8c975a624996e12de31abbe135d9d159375739c333798a80c64ae895e51e22f3ad",
+    },
+    Vector {
+        len: 5120,
+        hash: "9cadc15fed8b5d854562b26a9536d9707cadeda9b143978f319ab34230535833acc61c8fdc114a2010ce8038c853e121e1544985133fccdd0a2d507e8e615e611e9a0ba4f47915f49e53d721816a9198e8b30f12d20ec3689989175f1bf7a300eee0d9321fad8da232ece6efb8e9fd81b42ad161f6b9550a069e66b11b40487a5f5059",
+        keyed_hash: "2c493e48e9b9bf31e0553a22b23503c0a3388f035cece68eb438d22fa1943e209b4dc9209cd80ce7c1f7c9a744658e7e288465717ae6e56d5463d4f80cdb2ef56495f6a4f5487f69749af0c34c2cdfa857f3056bf8d807336a14d7b89bf62bef2fb54f9af6a546f818dc1e98b9e07f8a5834da50fa28fb5874af91bf06020d1bf0120e",
+        derive_key: "7a7acac8a02adcf3038d74cdd1d34527de8a0fcc0ee3399d1262397ce5817f6055d0cefd84d9d57fe792d65a278fd20384ac6c30fdb340092f1a74a92ace99c482b28f0fc0ef3b923e56ade20c6dba47e49227166251337d80a037e987ad3a7f728b5ab6dfafd6e2ab1bd583a95d9c895ba9c2422c24ea0f62961f0dca45cad47bfa0d",
+    },
+    Vector {
+        len: 5121,
+        hash: "628bd2cb2004694adaab7bbd778a25df25c47b9d4155a55f8fbd79f2fe154cff96adaab0613a6146cdaabe498c3a94e529d3fc1da2bd08edf54ed64d40dcd6777647eac51d8277d70219a9694334a68bc8f0f23e20b0ff70ada6f844542dfa32cd4204ca1846ef76d811cdb296f65e260227f477aa7aa008bac878f72257484f2b6c95",
+        keyed_hash: "6ccf1c34753e7a044db80798ecd0782a8f76f33563accaddbfbb2e0ea4b2d0240d07e63f13667a8d1490e5e04f13eb617aea16a8c8a5aaed1ef6fbde1b0515e3c81050b361af6ead126032998290b563e3caddeaebfab592e155f2e161fb7cba939092133f23f9e65245e58ec23457b78a2e8a125588aad6e07d7f11a85b88d375b72d",
+        derive_key: "b07f01e518e702f7ccb44a267e9e112d403a7b3f4883a47ffbed4b48339b3c341a0add0ac032ab5aaea1e4e5b004707ec5681ae0fcbe3796974c0b1cf31a194740c14519273eedaabec832e8a784b6e7cfc2c5952677e6c3f2c3914454082d7eb1ce1766ac7d75a4d3001fc89544dd46b5147382240d689bbbaefc359fb6ae30263165",
+    },
+    Vector {
+        len: 31744,
+        hash: "62b6960e1a44bcc1eb1a611a8d6235b6b4b78f32e7abc4fb4c6cdcce94895c47860cc51f2b0c28a7b77304bd55fe73af663c02d3f52ea053ba43431ca5bab7bfea2f5e9d7121770d88f70ae9649ea713087d1914f7f312147e247f87eb2d4ffef0ac978bf7b6579d57d533355aa20b8b77b13fd09748728a5cc327a8ec470f4013226f",
+        keyed_hash: "efa53b389ab67c593dba624d898d0f7353ab99e4ac9d42302ee64cbf9939a4193a7258db2d9cd32a7a3ecfce46144114b15c2fcb68a618a976bd74515d47be08b628be420b5e830fade7c080e351a076fbc38641ad80c736c8a18fe3c66ce12f95c61c2462a9770d60d0f77115bbcd3782b593016a4e728d4c06cee4505cb0c08a42ec",
+        derive_key: "39772aef80e0ebe60596361e45b061e8f417429d529171b6764468c22928e28e9759adeb797a3fbf771b1bcea30150a020e317982bf0d6e7d14dd9f064bc11025c25f31e81bd78a921db0174f03dd481d30e93fd8e90f8b2fee209f849f2d2a52f31719a490fb0ba7aea1e09814ee912eba111a9fde9d5c274185f7bae8ba85d300a2b",
+    },
+    Vector {
+        len: 102400,
+        hash: "bc3e3d41a1146b069abffad3c0d44860cf664390afce4d9661f7902e7943e085e01c59dab908c04c3342b816941a26d69c2605ebee5ec5291cc55e15b76146e6745f0601156c3596cb75065a9c57f35585a52e1ac70f69131c23d611ce11ee4ab1ec2c009012d236648e77be9295dd0426f29b764d65de58eb7d01dd42248204f45f8e",
+        keyed_hash: "1c35d1a5811083fd7119f5d5d1ba027b4d01c0c6c49fb6ff2cf75393ea5db4a7f9dbdd3e1d81dcbca3ba241bb18760f207710b751846faaeb9dff8262710999a59b2aa1aca298a032d94eacfadf1aa192418eb54808db23b56e34213266aa08499a16b354f018fc4967d05f8b9d2ad87a7278337be9693fc638a3bfdbe314574ee6fc4",
+        derive_key: "4652cff7a3f385a6103b5c260fc1593e13c778dbe608efb092fe7ee69df6e9c6d83a3e041bc3a48df2879f4a0a3ed40e7c961c73eff740f3117a0504c2dff4786d44fb17f1549eb0ba585e40ec29bf7732f0b7e286ff8acddc4cb1e23b87ff5d824a986458dcc6a04ac83969b80637562953df51ed1a7e90a7926924d2763778be8560",
+    },
+];
+
+/// Above [`PARALLEL_THRESHOLD`], so `update_parallel` really splits the input; computed with
+/// the reference implementation since the official file stops at 102400 bytes.
+const PARALLEL_VECTOR: Vector = Vector {
+    len: 263169,
+    hash: "5f939be9e0ff14200d35a1f37ac138d517c81ce2ddfd3b8b7a42c90be46e0105dca974282947ac033a129e87cdf2049ba9e386018727829899c86bb77a6acf5c9510f7677d3e80220fc10ddc8653def3bbc3f423f88a8943d46aa814f3cb34bd15824b383ffe7f2ab6f9d2754fbcbf2af4400aeadec3c0307f3a33b70223fec2601cce",
+    keyed_hash: "91d11e1140580397b3acb253eae326ab863087628af860630aa98e11832c59b25d67076a3b9f500842b21f8893ff6ab1c364993b48e7dd66eeca0d9d8310c8e31776183cf59018843709f5143cf3863d1184290fee8476837bb664e729a893b4fb2974805ca4532b7073a53afa08f604457829edb252ed28e553c9cbec5912adcf36ec",
+    derive_key: "04e6be6a12be0a33e5ead45389e2c1297e190df7acc5aee8cf465e7fb8a4cad45566f8baf66cf81bab4e565d682851abd4869b35e64e6e2da9c2c6cbcf314a6390270d720c0f676949625ef93d4b3c1de0bc814560233b248e48226685daee41abb984b899d963dd792e57ba1b045adc0226d385e029223f351bdd2b46183c7e948786",
+};
+
+/// Checks all three modes of `vector`, through `update` and through `update_parallel`.
+fn check_vector(vector: &Vector) {
+    let input = pattern(vector.len);
+    let modes = [
+        (Blake3::new(), vector.hash),
+        (Blake3::new_keyed(TEST_KEY), vector.keyed_hash),
+        (Blake3::new_derive_key(TEST_CONTEXT), vector.derive_key),
+    ];
+
+    for (hasher, expected) in modes {
+        let expected = hex(expected);
+
+        let mut serial = hasher.clone();
+        serial.update(&input);
+
+        let mut parallel = hasher;
+        parallel.update_parallel(&input);
+
+        for (name, hasher) in [("update", serial), ("update_parallel", parallel)] {
+            assert_eq!(
+                hasher.clone().finalize().0[..],
+                expected[..32],
+                "{name}, len {}",
+                vector.len
+            );
+
+            let mut out = vec![0u8; expected.len()];
+            hasher.finalize_xof().squeeze(&mut out);
+            assert_eq!(out, expected, "{name} xof, len {}", vector.len);
+        }
+    }
+}
+
+// -------------------------------------------------------
+// 1. OFFICIAL TEST VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn blake3_empty_vector() {
+    assert_eq!(
+        blake3(b"").0.to_vec(),
+        hex("af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262")
+    );
+}
+
+#[test]
+fn blake3_abc_vector() {
+    assert_eq!(
+        blake3(b"abc").0.to_vec(),
+        hex("6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85")
+    );
+}
+
+#[test]
+fn blake3_one_byte_vector() {
+    assert_eq!(
+        blake3(&pattern(1)).0.to_vec(),
+        hex("2d3adedff11b61f14c886e35afa036736dcd87a74d27b5c1510225d0f592e213")
+    );
+}
+
+#[test]
+fn blake3_derive_key_empty_vector() {
+    assert_eq!(
+        derive_key(TEST_CONTEXT, b"").to_vec(),
+        hex("2cc39783c223154fea8dfb7c1b1660f2ac2dcbd1c1de8277b0b0dd39b7e50d7d")
+    );
+}
+
+#[test]
+fn blake3_multi_chunk_vectors() {
+    for vector in &MULTI_CHUNK_VECTORS {
+        check_vector(vector);
+    }
+}
+
+#[test]
+fn blake3_keyed_hash_vector() {
+    assert_eq!(
+        keyed_hash(TEST_KEY, &pattern(1024)).0.to_vec(),
+        hex(&MULTI_CHUNK_VECTORS[0].keyed_hash[..64])
+    );
+}
+
+// -------------------------------------------------------
+// 2. STREAMING
+// -------------------------------------------------------
+
+#[test]
+fn blake3_stream_matches_one_shot_across_chunk_boundaries() {
+    let buf = pattern(5 * CHUNK_LEN + 17);
+    let expected = blake3(&buf);
+
+    for split in [
+        0,
+        1,
+        63,
+        64,
+        65,
+        1023,
+        1024,
+        1025,
+        2048,
+        3072,
+        4097,
+        buf.len(),
+    ] {
+        let mut hasher = Blake3::new();
+        hasher.update(&buf[..split]);
+        hasher.update(&buf[split..]);
+        assert_eq!(hasher.finalize(), expected, "split {split}");
+    }
+}
+
+#[test]
+fn blake3_byte_by_byte_matches_one_shot() {
+    let buf = pattern(3 * CHUNK_LEN + 1);
+
+    let mut hasher = Blake3::new();
+    for byte in &buf {
+        hasher.update(std::slice::from_ref(byte));
+    }
+
+    assert_eq!(hasher.finalize(), blake3(&buf));
+}
+
+#[test]
+fn blake3_reset_keeps_key() {
+    let mut hasher = Blake3::new_keyed(TEST_KEY);
+    hasher.update(b"discarded");
+    hasher.reset();
+    hasher.update(b"abc");
+
+    assert_eq!(hasher.finalize(), keyed_hash(TEST_KEY, b"abc"));
+}
+
+// -------------------------------------------------------
+// 3. MODES
+// -------------------------------------------------------
+
+#[test]
+fn blake3_modes_are_domain_separated() {
+    let input = pattern(2000);
+    let plain = blake3(&input);
+    let keyed = keyed_hash(TEST_KEY, &input);
+    let derived = derive_key(TEST_CONTEXT, &input);
+
+    assert_ne!(plain, keyed);
+    assert_ne!(plain.0, derived);
+    assert_ne!(keyed.0, derived);
+}
+
+#[test]
+fn blake3_keyed_stream_matches_one_shot() {
+    let input = pattern(3000);
+
+    let mut hasher = Blake3::new_keyed(TEST_KEY);
+    hasher.update(&input[..1500]);
+    hasher.update(&input[1500..]);
+
+    assert_eq!(hasher.finalize(), keyed_hash(TEST_KEY, &input));
+}
+
+#[test]
+fn blake3_derive_key_depends_on_context() {
+    assert_ne!(
+        derive_key(TEST_CONTEXT, b"material"),
+        derive_key("another context", b"material")
+    );
+}
+
+// -------------------------------------------------------
+// 4. EXTENDABLE OUTPUT
+// -------------------------------------------------------
+
+#[test]
+fn blake3_xof_prefix_is_default_output() {
+    for len in [0, 1, 1024, 1025, 5000] {
+        let input = pattern(len);
+
+        let mut hasher = Blake3::new();
+        hasher.update(&input);
+
+        let mut out = [0u8; 131];
+        hasher.finalize_xof().squeeze(&mut out);
+        assert_eq!(&out[..32], &blake3(&input).0[..], "len {len}");
+    }
+}
+
+#[test]
+fn blake3_xof_small_squeezes_match_large_one() {
+    let mut hasher = Blake3::new();
+    hasher.update(b"abc");
+
+    let mut expected = [0u8; 300];
+    hasher.clone().finalize_xof().squeeze(&mut expected);
+
+    let mut reader = hasher.finalize_xof();
+    let mut out = Vec::new();
+    for size in [1, 63, 64, 7, 100, 65] {
+        let mut part = vec![0u8; size];
+        reader.squeeze(&mut part);
+        out.extend_from_slice(&part);
+    }
+
+    assert_eq!(out, expected);
+    assert_eq!(reader.position(), 300);
+}
+
+#[test]
+fn blake3_xof_seek() {
+    let mut hasher = Blake3::new();
+    hasher.update(b"seek");
+
+    let mut full = [0u8; 256];
+    hasher.clone().finalize_xof().squeeze(&mut full);
+
+    let mut reader = hasher.finalize_xof();
+    reader.set_position(100);
+    let mut tail = [0u8; 156];
+    reader.squeeze(&mut tail);
+
+    assert_eq!(&tail[..], &full[100..]);
+}
+
+// -------------------------------------------------------
+// 5. PARALLEL UPDATE
+// -------------------------------------------------------
+
+#[test]
+fn blake3_parallel_reference_vector() {
+    check_vector(&PARALLEL_VECTOR);
+}
+
+#[test]
+fn blake3_parallel_matches_serial() {
+    let buf = pattern(300 * 1024 + 5);
+
+    for prefix in [0, 1, 1024, 1500] {
+        let mut serial = Blake3::new();
+        serial.update(&buf[..prefix]);
+        serial.update(&buf[prefix..]);
+
+        let mut parallel = Blake3::new();
+        parallel.update(&buf[..prefix]);
+        parallel.update_parallel(&buf[prefix..]);
+
+        assert_eq!(parallel.finalize(), serial.finalize(), "prefix {prefix}");
+    }
+}
+
+#[test]
+fn blake3_parallel_exact_chunk_multiple() {
+    let buf = pattern(PARALLEL_THRESHOLD * 2);
+
+    let mut hasher = Blake3::new();
+    hasher.update_parallel(&buf);
+    hasher.update(b"tail");
+
+    let mut expected = buf.clone();
+    expected.extend_from_slice(b"tail");
+
+    assert_eq!(hasher.finalize(), blake3(&expected));
+}
+
+#[test]
+fn blake3_parallel_small_input_falls_back() {
+    let buf = pattern(PARALLEL_THRESHOLD - 1);
+
+    let mut hasher = Blake3::new_keyed(TEST_KEY);
+    hasher.update_parallel(&buf);
+
+    assert_eq!(hasher.finalize(), keyed_hash(TEST_KEY, &buf));
+}
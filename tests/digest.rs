@@ -0,0 +1,150 @@
+use cryptography::hash::digest::{Digest, DynDigest};
+use cryptography::hash::{
+    Blake2b512, Blake2s256, Blake3, Keccak256, Output, Ripemd160, Sha3_224, Sha3_256, Sha3_384,
+    Sha3_512, Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256, blake2b512, keccak256,
+    ripemd160, sha224, sha256, sha256_digest,
+};
+
+fn message() -> Vec<u8> {
+    (0..1500u32).map(|i| (i * 31 + 7) as u8).collect()
+}
+
+/// Checks the streaming, one-shot and reset paths of `D` against each other.
+fn check_consistency<D: Digest + 'static>() {
+    let msg = message();
+    let expected = D::digest(&msg);
+
+    assert_eq!(expected.as_ref().len(), D::OUTPUT_SIZE);
+    assert!(D::BLOCK_SIZE > 0);
+
+    // Streaming must not depend on how the input is split
+    for split in [
+        0,
+        1,
+        D::BLOCK_SIZE - 1,
+        D::BLOCK_SIZE,
+        D::BLOCK_SIZE + 1,
+        1024,
+        msg.len(),
+    ] {
+        let mut hasher = D::new();
+        hasher.update(&msg[..split]);
+        hasher.update(&msg[split..]);
+        assert!(hasher.finalize() == expected, "split {split}");
+    }
+
+    // finalize_reset returns the digest and leaves a fresh hasher behind
+    let mut hasher = D::new();
+    hasher.update(b"discarded");
+    assert!(hasher.finalize_reset() == D::digest(b"discarded"));
+    hasher.update(&msg);
+    assert!(hasher.finalize_reset() == expected);
+    assert!(hasher.finalize() == D::digest(b""));
+
+    // The object-safe interface agrees with the static one
+    let mut dynamic: Box<dyn DynDigest> = Box::new(D::new());
+    assert_eq!(dynamic.output_size(), D::OUTPUT_SIZE);
+    assert_eq!(dynamic.block_size(), D::BLOCK_SIZE);
+
+    dynamic.update(&msg[..700]);
+    let forked = dynamic.clone();
+    dynamic.update(&msg[700..]);
+    assert_eq!(dynamic.finalize_reset(), expected.as_ref());
+
+    dynamic.update(b"discarded");
+    dynamic.reset();
+    assert_eq!(dynamic.finalize_boxed(), D::digest(b"").as_ref());
+
+    let mut forked = forked;
+    forked.update(&msg[700..]);
+    assert_eq!(forked.finalize_boxed(), expected.as_ref());
+}
+
+// -------------------------------------------------------
+// 1. CONSISTENCY OF EVERY IMPLEMENTATION
+// -------------------------------------------------------
+
+#[test]
+fn digest_sha2_family() {
+    check_consistency::<Sha224>();
+    check_consistency::<Sha256>();
+    check_consistency::<Sha384>();
+    check_consistency::<Sha512>();
+    check_consistency::<Sha512_224>();
+    check_consistency::<Sha512_256>();
+}
+
+#[test]
+fn digest_sha3_family() {
+    check_consistency::<Sha3_224>();
+    check_consistency::<Sha3_256>();
+    check_consistency::<Sha3_384>();
+    check_consistency::<Sha3_512>();
+    check_consistency::<Keccak256>();
+}
+
+#[test]
+fn digest_blake_family() {
+    check_consistency::<Blake2b512>();
+    check_consistency::<Blake2s256>();
+    check_consistency::<Blake3>();
+}
+
+#[test]
+fn digest_ripemd160() {
+    check_consistency::<Ripemd160>();
+}
+
+// -------------------------------------------------------
+// 2. AGREEMENT WITH THE INHERENT API
+// -------------------------------------------------------
+
+#[test]
+fn digest_matches_sha256_function() {
+    assert_eq!(<Sha256 as Digest>::digest(b"abc"), sha256_digest(b"abc"));
+    assert_eq!(<Sha256 as Digest>::digest(b"abc").to_u256(), sha256(b"abc"));
+}
+
+#[test]
+fn digest_outputs_share_one_wrapper() {
+    let short: Output<20> = Ripemd160::digest(b"abc");
+    let truncated: Output<28> = Sha224::digest(b"abc");
+    let wide: Output<32> = Keccak256::digest(b"abc");
+    let long: Output<64> = Blake2b512::digest(b"abc");
+
+    assert_eq!(short.0, ripemd160(b"abc"));
+    assert_eq!(truncated.0, sha224(b"abc").0);
+    assert_eq!(wide.to_u256(), keccak256(b"abc"));
+    assert_eq!(long.0, blake2b512(b"abc").0);
+
+    assert!(long.ct_eq(&Blake2b512::digest(b"abc")));
+    assert_eq!(short.to_string().len(), 40);
+}
+
+#[test]
+fn digest_sizes() {
+    assert_eq!(<Sha256 as Digest>::OUTPUT_SIZE, 32);
+    assert_eq!(<Sha256 as Digest>::BLOCK_SIZE, 64);
+    assert_eq!(<Sha512 as Digest>::BLOCK_SIZE, 128);
+    assert_eq!(<Sha3_256 as Digest>::BLOCK_SIZE, 136);
+    assert_eq!(<Ripemd160 as Digest>::OUTPUT_SIZE, 20);
+}
+
+#[test]
+fn dyn_digest_runtime_selection() {
+    let hashers: Vec<Box<dyn DynDigest>> = vec![
+        Box::new(Sha256::new()),
+        Box::new(Sha3_512::new()),
+        Box::new(Blake3::new()),
+    ];
+
+    let sizes: Vec<usize> = hashers
+        .into_iter()
+        .map(|mut h| {
+            h.update(b"abc");
+            h.finalize_boxed().len()
+        })
+        .collect();
+
+    assert_eq!(sizes, vec![32, 64, 32]);
+}
@@ -0,0 +1,150 @@
+use cryptography::hash::{Sha256, Sha512};
+use cryptography::kdf::hkdf::{HkdfError, expand, extract, hkdf};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+struct Case {
+    ikm: Vec<u8>,
+    salt: Vec<u8>,
+    info: Vec<u8>,
+    prk: &'static str,
+    okm: &'static str,
+}
+
+/// RFC 5869 appendix A, test cases 1 to 3 (SHA-256).
+fn rfc5869_cases() -> Vec<Case> {
+    vec![
+        Case {
+            ikm: vec![0x0b; 22],
+            salt: (0x00..=0x0c).collect(),
+            info: (0xf0..=0xf9).collect(),
+            prk: "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5",
+            okm: "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865",
+        },
+        Case {
+            ikm: (0x00..=0x4f).collect(),
+            salt: (0x60..=0xaf).collect(),
+            info: (0xb0..=0xff).collect(),
+            prk: "06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244",
+            okm: "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87",
+        },
+        Case {
+            ikm: vec![0x0b; 22],
+            salt: vec![],
+            info: vec![],
+            prk: "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04",
+            okm: "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8",
+        },
+    ]
+}
+
+// -------------------------------------------------------
+// 1. RFC 5869 VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn hkdf_sha256_rfc5869_extract() {
+    for (i, case) in rfc5869_cases().iter().enumerate() {
+        let prk = extract::<Sha256>(&case.salt, &case.ikm);
+        assert_eq!(prk.as_ref(), &hex(case.prk)[..], "test case {}", i + 1);
+    }
+}
+
+#[test]
+fn hkdf_sha256_rfc5869_expand() {
+    for (i, case) in rfc5869_cases().iter().enumerate() {
+        let okm = hex(case.okm);
+        let got = expand::<Sha256>(&hex(case.prk), &case.info, okm.len()).unwrap();
+        assert_eq!(got, okm, "test case {}", i + 1);
+    }
+}
+
+#[test]
+fn hkdf_sha256_rfc5869_combined() {
+    for (i, case) in rfc5869_cases().iter().enumerate() {
+        let okm = hex(case.okm);
+        let got = hkdf::<Sha256>(&case.salt, &case.ikm, &case.info, okm.len()).unwrap();
+        assert_eq!(got, okm, "test case {}", i + 1);
+    }
+}
+
+// -------------------------------------------------------
+// 2. LIMITS AND CONSISTENCY
+// -------------------------------------------------------
+
+#[test]
+fn hkdf_output_limit() {
+    let prk = extract::<Sha256>(b"salt", b"ikm");
+
+    assert_eq!(
+        expand::<Sha256>(prk.as_ref(), b"", 255 * 32).unwrap().len(),
+        255 * 32
+    );
+    assert_eq!(
+        expand::<Sha256>(prk.as_ref(), b"", 255 * 32 + 1),
+        Err(HkdfError::OutputTooLong {
+            requested: 255 * 32 + 1,
+            max: 255 * 32
+        })
+    );
+    assert_eq!(
+        hkdf::<Sha512>(b"", b"ikm", b"", 255 * 64 + 1),
+        Err(HkdfError::OutputTooLong {
+            requested: 255 * 64 + 1,
+            max: 255 * 64
+        })
+    );
+}
+
+#[test]
+fn hkdf_rejects_huge_output_before_allocating() {
+    let prk = extract::<Sha256>(b"salt", b"ikm");
+
+    assert_eq!(
+        expand::<Sha256>(prk.as_ref(), b"", usize::MAX),
+        Err(HkdfError::OutputTooLong {
+            requested: usize::MAX,
+            max: 255 * 32
+        })
+    );
+    assert_eq!(
+        hkdf::<Sha512>(b"", b"ikm", b"", usize::MAX),
+        Err(HkdfError::OutputTooLong {
+            requested: usize::MAX,
+            max: 255 * 64
+        })
+    );
+}
+
+#[test]
+fn hkdf_rejects_short_prk() {
+    assert_eq!(
+        expand::<Sha256>(&[0u8; 31], b"", 32),
+        Err(HkdfError::PrkTooShort(31))
+    );
+}
+
+#[test]
+fn hkdf_shorter_outputs_are_prefixes() {
+    let long = hkdf::<Sha256>(b"salt", b"ikm", b"info", 100).unwrap();
+
+    for len in [0, 1, 31, 32, 33, 64, 99] {
+        assert_eq!(
+            hkdf::<Sha256>(b"salt", b"ikm", b"info", len).unwrap(),
+            long[..len]
+        );
+    }
+}
+
+#[test]
+fn hkdf_info_separates_outputs() {
+    assert_ne!(
+        hkdf::<Sha256>(b"salt", b"ikm", b"session", 32).unwrap(),
+        hkdf::<Sha256>(b"salt", b"ikm", b"channel", 32).unwrap()
+    );
+}
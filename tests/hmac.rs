@@ -0,0 +1,166 @@
+use cryptography::hash::{Sha3_256, Sha256, Sha512};
+use cryptography::mac::{Hmac, MacError, hmac_sha256};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// RFC 4231 test cases 1 to 7: (key, data, HMAC-SHA-256).
+fn rfc4231_cases() -> Vec<(Vec<u8>, Vec<u8>, &'static str)> {
+    vec![
+        (
+            vec![0x0b; 20],
+            b"Hi There".to_vec(),
+            "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+        ),
+        (
+            b"Jefe".to_vec(),
+            b"what do ya want for nothing?".to_vec(),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+        ),
+        (
+            vec![0xaa; 20],
+            vec![0xdd; 50],
+            "773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe",
+        ),
+        (
+            (1..=25).collect(),
+            vec![0xcd; 50],
+            "82558a389a443c0ea4cc819899f2083a85f0faa3e578f8077a2e3ff46729665b",
+        ),
+        (
+            vec![0x0c; 20],
+            b"Test With Truncation".to_vec(),
+            "a3b6167473100ee06e0c796c2955552bfa6f7c0a6a8aef8b93f860aab0cd20c5",
+        ),
+        (
+            vec![0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First".to_vec(),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+        ),
+        (
+            vec![0xaa; 131],
+            b"This is a test using a larger than block-size key and a larger than block-size data. The key needs to be hashed before being used by the HMAC algorithm.".to_vec(),
+            "9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2",
+        ),
+    ]
+}
+
+// -------------------------------------------------------
+// 1. RFC 4231 VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn hmac_sha256_rfc4231() {
+    for (i, (key, data, expected)) in rfc4231_cases().into_iter().enumerate() {
+        assert_eq!(
+            hmac_sha256(&key, &data).to_string(),
+            expected,
+            "test case {}",
+            i + 1
+        );
+    }
+}
+
+#[test]
+fn hmac_sha256_rfc4231_truncated() {
+    // Test case 5 specifies the output truncated to 128 bits
+    let mut mac = Hmac::<Sha256>::new(&[0x0c; 20]);
+    mac.update(b"Test With Truncation");
+
+    let truncated = hex("a3b6167473100ee06e0c796c2955552b");
+    assert_eq!(mac.clone().finalize().as_ref()[..16], truncated[..]);
+    assert_eq!(mac.verify_truncated(&truncated), Ok(()));
+}
+
+#[test]
+fn hmac_sha512_rfc4231_case2_prefix() {
+    let mut mac = Hmac::<Sha512>::new(b"Jefe");
+    mac.update(b"what do ya want for nothing?");
+
+    assert_eq!(
+        mac.finalize().0[..16].to_vec(),
+        hex("164b7a7bfcf819e2e395fbe73b56e0a3")
+    );
+}
+
+// -------------------------------------------------------
+// 2. STREAMING
+// -------------------------------------------------------
+
+#[test]
+fn hmac_stream_matches_one_shot() {
+    for (key, data, expected) in rfc4231_cases() {
+        for split in [0, 1, data.len() / 2, data.len()] {
+            let mut mac = Hmac::<Sha256>::new(&key);
+            mac.update(&data[..split]);
+            mac.update(&data[split..]);
+            assert_eq!(mac.finalize().to_string(), expected, "split {split}");
+        }
+    }
+}
+
+#[test]
+fn hmac_block_size_key_is_not_hashed() {
+    // A 64-byte key is used as is; a 65-byte key is hashed first
+    let key64 = [0x42u8; 64];
+    let key65 = [0x42u8; 65];
+
+    assert_ne!(hmac_sha256(&key64, b"m"), hmac_sha256(&key65, b"m"));
+    assert_ne!(hmac_sha256(&key64, b"m"), hmac_sha256(&key64[..63], b"m"));
+}
+
+#[test]
+fn hmac_generic_over_digest() {
+    let mut a = Hmac::<Sha3_256>::new(b"key");
+    a.update(b"message");
+    let tag = a.finalize();
+
+    let mut b = Hmac::<Sha3_256>::new(b"key");
+    b.update(b"message");
+    assert_eq!(b.verify(tag.as_ref()), Ok(()));
+}
+
+// -------------------------------------------------------
+// 3. VERIFICATION
+// -------------------------------------------------------
+
+#[test]
+fn hmac_verify_accepts_and_rejects() {
+    let tag = hmac_sha256(b"key", b"message");
+
+    let mut mac = Hmac::<Sha256>::new(b"key");
+    mac.update(b"message");
+    assert_eq!(mac.clone().verify(tag.as_ref()), Ok(()));
+
+    let mut forged = tag.0;
+    forged[31] ^= 1;
+    assert_eq!(mac.clone().verify(&forged), Err(MacError::TagMismatch));
+
+    assert_eq!(
+        mac.verify(&tag.0[..31]),
+        Err(MacError::InvalidTagLength(31))
+    );
+}
+
+#[test]
+fn hmac_verify_truncated_limits() {
+    let tag = hmac_sha256(b"key", b"message");
+    let mac = Hmac::<Sha256>::new(b"key");
+    let mut mac_msg = mac.clone();
+    mac_msg.update(b"message");
+
+    assert_eq!(mac_msg.clone().verify_truncated(&tag.0[..16]), Ok(()));
+    assert_eq!(mac_msg.clone().verify_truncated(&tag.0), Ok(()));
+    assert_eq!(
+        mac_msg.clone().verify_truncated(&tag.0[..15]),
+        Err(MacError::InvalidTagLength(15))
+    );
+    assert_eq!(
+        mac.verify_truncated(&tag.0[..16]),
+        Err(MacError::TagMismatch)
+    );
+}
@@ -0,0 +1,174 @@
+use cryptography::hash::digest::Digest;
+use cryptography::hash::io::{HashingReader, HashingWriter, READ_BUFFER_LEN, sha256_reader};
+use cryptography::hash::{Sha3_256, Sha256, sha3_256, sha256};
+
+use std::io::{self, Cursor, ErrorKind, Read, Write};
+
+fn sample(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 13 + 5) as u8).collect()
+}
+
+/// Reader that returns `Interrupted` once, then small chunks, then optionally fails.
+struct FlakyReader {
+    data: Vec<u8>,
+    pos: usize,
+    interrupted: bool,
+    fail_at_end: bool,
+}
+
+impl Read for FlakyReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if !self.interrupted {
+            self.interrupted = true;
+            return Err(io::Error::from(ErrorKind::Interrupted));
+        }
+
+        if self.pos == self.data.len() {
+            if self.fail_at_end {
+                return Err(io::Error::other("disk unplugged"));
+            }
+            return Ok(0);
+        }
+
+        let n = buf.len().min(7).min(self.data.len() - self.pos);
+        buf[..n].copy_from_slice(&self.data[self.pos..self.pos + n]);
+        self.pos += n;
+
+        Ok(n)
+    }
+}
+
+/// Writer that accepts at most 5 bytes per call.
+struct SlowWriter(Vec<u8>);
+
+impl Write for SlowWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = buf.len().min(5);
+        self.0.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// -------------------------------------------------------
+// 1. WRITE FOR SHA-256
+// -------------------------------------------------------
+
+#[test]
+fn sha256_write_matches_one_shot() {
+    let data = sample(5000);
+
+    let mut hasher = Sha256::new();
+    io::copy(&mut Cursor::new(&data), &mut hasher).unwrap();
+    hasher.write_all(b"tail").unwrap();
+    hasher.flush().unwrap();
+
+    let mut expected = data.clone();
+    expected.extend_from_slice(b"tail");
+    assert_eq!(hasher.finalize(), sha256(&expected));
+}
+
+// -------------------------------------------------------
+// 2. READER HASHING
+// -------------------------------------------------------
+
+#[test]
+fn sha256_reader_matches_one_shot() {
+    for len in [
+        0,
+        1,
+        64,
+        READ_BUFFER_LEN - 1,
+        READ_BUFFER_LEN,
+        3 * READ_BUFFER_LEN + 17,
+    ] {
+        let data = sample(len);
+        assert_eq!(
+            sha256_reader(&data[..]).unwrap(),
+            sha256(&data),
+            "len {len}"
+        );
+    }
+}
+
+#[test]
+fn sha256_reader_retries_interrupted() {
+    let data = sample(100);
+    let reader = FlakyReader {
+        data: data.clone(),
+        pos: 0,
+        interrupted: false,
+        fail_at_end: false,
+    };
+
+    assert_eq!(sha256_reader(reader).unwrap(), sha256(&data));
+}
+
+#[test]
+fn sha256_reader_propagates_errors() {
+    let reader = FlakyReader {
+        data: sample(100),
+        pos: 0,
+        interrupted: true,
+        fail_at_end: true,
+    };
+
+    let err = sha256_reader(reader).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Other);
+    assert_eq!(err.to_string(), "disk unplugged");
+}
+
+// -------------------------------------------------------
+// 3. TEE ADAPTERS
+// -------------------------------------------------------
+
+#[test]
+fn hashing_reader_hashes_what_passes_through() {
+    let data = sample(10_000);
+
+    let mut reader = HashingReader::<_, Sha256>::new(Cursor::new(&data));
+    let mut copy = Vec::new();
+    reader.read_to_end(&mut copy).unwrap();
+
+    let (_, digest) = reader.finalize();
+    assert_eq!(copy, data);
+    assert_eq!(digest, Sha256::digest(&data));
+}
+
+#[test]
+fn hashing_reader_is_generic_over_digest() {
+    let data = sample(300);
+
+    let mut reader = HashingReader::<_, Sha3_256>::new(&data[..]);
+    io::copy(&mut reader, &mut io::sink()).unwrap();
+
+    assert_eq!(reader.finalize().1.to_u256(), sha3_256(&data));
+}
+
+#[test]
+fn hashing_writer_counts_only_accepted_bytes() {
+    let data = sample(1234);
+
+    let mut writer = HashingWriter::<_, Sha256>::new(SlowWriter(Vec::new()));
+    writer.write_all(&data).unwrap();
+    writer.flush().unwrap();
+
+    let (inner, digest) = writer.finalize();
+    assert_eq!(inner.0, data);
+    assert_eq!(digest.to_u256(), sha256(&data));
+}
+
+#[test]
+fn hashing_writer_propagates_errors() {
+    let mut buf = [0u8; 4];
+    let mut writer = HashingWriter::<_, Sha256>::new(&mut buf[..]);
+
+    let err = writer.write_all(b"too long").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WriteZero);
+
+    // The four bytes that fit are the ones hashed
+    assert_eq!(writer.finalize().1.to_u256(), sha256(b"too "));
+}
@@ -0,0 +1,96 @@
+use cryptography::hash::keccak256::{AddressError, eth_address, to_checksum_address};
+use cryptography::hash::{Keccak256, keccak256, sha3_256};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn address(s: &str) -> [u8; 20] {
+    let mut out = [0u8; 20];
+    out.copy_from_slice(&hex(&s[2..].to_ascii_lowercase()));
+    out
+}
+
+// -------------------------------------------------------
+// 1. KECCAK-256 VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn keccak256_vectors() {
+    assert_eq!(
+        keccak256(&[]).0.to_vec(),
+        hex("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+    );
+    assert_eq!(
+        keccak256(b"abc").0.to_vec(),
+        hex("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45")
+    );
+}
+
+#[test]
+fn keccak256_differs_from_sha3_256() {
+    assert_ne!(keccak256(b"abc"), sha3_256(b"abc"));
+}
+
+#[test]
+fn keccak256_stream_matches_one_shot() {
+    let buf: Vec<u8> = (0..500u32).map(|i| (i * 11) as u8).collect();
+
+    for step in [1, 135, 136, 137, 499] {
+        let mut hasher = Keccak256::new();
+        for chunk in buf.chunks(step) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.finalize(), keccak256(&buf), "step {step}");
+    }
+}
+
+// -------------------------------------------------------
+// 2. ADDRESS DERIVATION
+// -------------------------------------------------------
+
+// Generator point G of secp256k1, i.e. the public key of private key 1
+const G_UNCOMPRESSED: &str = "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
+#[test]
+fn eth_address_of_generator() {
+    let key = hex(G_UNCOMPRESSED);
+    let addr = eth_address(&key).unwrap();
+
+    assert_eq!(
+        to_checksum_address(&addr),
+        "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf"
+    );
+    assert_eq!(eth_address(&key[1..]).unwrap(), addr);
+}
+
+#[test]
+fn eth_address_rejects_malformed_keys() {
+    let mut key = hex(G_UNCOMPRESSED);
+    assert_eq!(
+        eth_address(&key[..33]),
+        Err(AddressError::InvalidLength(33))
+    );
+
+    key[0] = 0x02;
+    assert_eq!(eth_address(&key), Err(AddressError::InvalidPrefix(0x02)));
+}
+
+// -------------------------------------------------------
+// 3. EIP-55 CHECKSUMS
+// -------------------------------------------------------
+
+#[test]
+fn eip55_reference_addresses() {
+    for expected in [
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ] {
+        assert_eq!(to_checksum_address(&address(expected)), expected);
+    }
+}
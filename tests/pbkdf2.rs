@@ -0,0 +1,175 @@
+use cryptography::hash::{Sha256, Sha512};
+use cryptography::kdf::pbkdf2::{
+    Pbkdf2Error, pbkdf2, pbkdf2_hmac_sha256, pbkdf2_hmac_sha512, pbkdf2_into, pbkdf2_parallel,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+// -------------------------------------------------------
+// 1. RFC 7914 SECTION 11 (PBKDF2-HMAC-SHA256)
+// -------------------------------------------------------
+
+#[test]
+fn pbkdf2_sha256_rfc7914_one_round() {
+    assert_eq!(
+        pbkdf2_hmac_sha256(b"passwd", b"salt", 1, 64).unwrap(),
+        hex(
+            "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+        )
+    );
+}
+
+#[test]
+fn pbkdf2_sha256_rfc7914_80000_rounds() {
+    assert_eq!(
+        pbkdf2_hmac_sha256(b"Password", b"NaCl", 80000, 64).unwrap(),
+        hex(
+            "4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d"
+        )
+    );
+}
+
+// -------------------------------------------------------
+// 2. RFC 6070-STYLE VECTORS (SHA-256 / SHA-512)
+// -------------------------------------------------------
+
+#[test]
+fn pbkdf2_sha256_rfc6070_style() {
+    let cases: [(&[u8], &[u8], u32, &str); 4] = [
+        (
+            b"password",
+            b"salt",
+            1,
+            "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b",
+        ),
+        (
+            b"password",
+            b"salt",
+            4096,
+            "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a",
+        ),
+        (
+            b"passwordPASSWORDpassword",
+            b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+            4096,
+            "348c89dbcbd32b2f32d814b8116e84cf2b17347ebc1800181c4e2a1fb8dd53e1c635518c7dac47e9",
+        ),
+        (
+            b"pass\0word",
+            b"sa\0lt",
+            4096,
+            "89b69d0516f829893c696226650a8687",
+        ),
+    ];
+
+    for (password, salt, rounds, expected) in cases {
+        let expected = hex(expected);
+        assert_eq!(
+            pbkdf2::<Sha256>(password, salt, rounds, expected.len()).unwrap(),
+            expected,
+            "rounds {rounds}"
+        );
+    }
+}
+
+#[test]
+fn pbkdf2_sha512_vectors() {
+    assert_eq!(
+        pbkdf2_hmac_sha512(b"password", b"salt", 1, 64).unwrap(),
+        hex(
+            "867f70cf1ade02cff3752599a3a53dc4af34c7a669815ae5d513554e1c8cf252c02d470a285a0501bad999bfe943c08f050235d7d68b1da55e63f73b60a57fce"
+        )
+    );
+    assert_eq!(
+        pbkdf2_hmac_sha512(b"password", b"salt", 4096, 64).unwrap(),
+        hex(
+            "d197b1b33db0143e018b12f3d1d1479e6cdebdcc97c5c0f87f6902e072f457b5143f30602641b3d55cd335988cb36b84376060ecd532e039b742a239434af2d5"
+        )
+    );
+}
+
+// -------------------------------------------------------
+// 3. PARALLEL VARIANT AND CONSISTENCY
+// -------------------------------------------------------
+
+#[test]
+fn pbkdf2_parallel_matches_serial() {
+    for out_len in [1, 31, 32, 33, 64, 100, 32 * 7 + 5] {
+        let serial = pbkdf2::<Sha256>(b"password", b"salt", 50, out_len).unwrap();
+
+        for threads in [0, 1, 2, 3, 4, 16] {
+            assert_eq!(
+                pbkdf2_parallel::<Sha256>(b"password", b"salt", 50, out_len, threads).unwrap(),
+                serial,
+                "out_len {out_len}, threads {threads}"
+            );
+        }
+    }
+
+    assert_eq!(
+        pbkdf2_parallel::<Sha512>(b"password", b"salt", 4096, 64 * 3, 3).unwrap()[..64],
+        pbkdf2_hmac_sha512(b"password", b"salt", 4096, 64).unwrap()[..]
+    );
+}
+
+#[test]
+fn pbkdf2_into_matches_vec() {
+    let mut out = [0u8; 40];
+    pbkdf2_into::<Sha256>(b"password", b"salt", 10, &mut out).unwrap();
+
+    assert_eq!(
+        out.to_vec(),
+        pbkdf2::<Sha256>(b"password", b"salt", 10, 40).unwrap()
+    );
+    assert!(
+        pbkdf2::<Sha256>(b"password", b"salt", 10, 0)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn pbkdf2_rejects_zero_rounds() {
+    let zero = Err(Pbkdf2Error::InvalidRounds(0));
+
+    assert_eq!(pbkdf2::<Sha256>(b"password", b"salt", 0, 32), zero);
+    assert_eq!(pbkdf2_hmac_sha256(b"password", b"salt", 0, 32), zero);
+    assert_eq!(pbkdf2_hmac_sha512(b"password", b"salt", 0, 64), zero);
+    assert_eq!(
+        pbkdf2_parallel::<Sha256>(b"password", b"salt", 0, 64, 2),
+        zero
+    );
+    assert_eq!(
+        pbkdf2_into::<Sha256>(b"password", b"salt", 0, &mut [0u8; 32]),
+        Err(Pbkdf2Error::InvalidRounds(0))
+    );
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn pbkdf2_rejects_too_long_output() {
+    // One byte past (2^32 - 1) blocks; rejected before anything is allocated or hashed
+    let too_long = u32::MAX as usize * 32 + 1;
+
+    assert_eq!(
+        pbkdf2_parallel::<Sha256>(b"password", b"salt", 1, too_long, 2),
+        Err(Pbkdf2Error::OutputTooLong(too_long))
+    );
+    assert_eq!(
+        pbkdf2::<Sha256>(b"password", b"salt", 1, too_long),
+        Err(Pbkdf2Error::OutputTooLong(too_long))
+    );
+    assert_eq!(
+        pbkdf2::<Sha256>(b"password", b"salt", 1, usize::MAX),
+        Err(Pbkdf2Error::OutputTooLong(usize::MAX))
+    );
+    assert_eq!(
+        pbkdf2_hmac_sha512(b"password", b"salt", 1, usize::MAX),
+        Err(Pbkdf2Error::OutputTooLong(usize::MAX))
+    );
+}
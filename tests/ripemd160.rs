@@ -0,0 +1,115 @@
+use cryptography::hash::bitcoin::{reverse_bytes, txid_hex};
+use cryptography::hash::{Ripemd160, hash160, ripemd160, sha256, sha256d};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+// -------------------------------------------------------
+// 1. RIPEMD-160 REFERENCE VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn ripemd160_empty_vector() {
+    assert_eq!(
+        ripemd160(b"").to_vec(),
+        hex("9c1185a5c5e9fc54612808977ee8f548b2258d31")
+    );
+}
+
+#[test]
+fn ripemd160_abc_vector() {
+    assert_eq!(
+        ripemd160(b"abc").to_vec(),
+        hex("8eb208f7e05d987a9b044a8e98c6b087f15a0bfc")
+    );
+}
+
+#[test]
+fn ripemd160_message_digest_vector() {
+    assert_eq!(
+        ripemd160(b"message digest").to_vec(),
+        hex("5d0689ef49d2fae572b881b123a85ffa21595f36")
+    );
+}
+
+#[test]
+fn ripemd160_million_a() {
+    let buf = vec![b'a'; 1_000_000];
+    assert_eq!(
+        ripemd160(&buf).to_vec(),
+        hex("52783243c1697bdbe16d37f97f68f08325dc1528")
+    );
+}
+
+// -------------------------------------------------------
+// 2. STREAMING
+// -------------------------------------------------------
+
+#[test]
+fn ripemd160_stream_matches_one_shot() {
+    let buf: Vec<u8> = (0..200u32).map(|i| i as u8).collect();
+    let expected = hex("c315823ea8fe07a2dd18de4e545255afe3af0738");
+
+    for split in [0, 1, 55, 56, 63, 64, 65, 128, 199, 200] {
+        let mut hasher = Ripemd160::new();
+        hasher.update(&buf[..split]);
+        hasher.update(&buf[split..]);
+        assert_eq!(hasher.finalize().to_vec(), expected, "split {split}");
+    }
+}
+
+#[test]
+fn ripemd160_reset_restores_iv() {
+    let mut hasher = Ripemd160::new();
+    hasher.update(b"discarded");
+    hasher.reset();
+    hasher.update(b"abc");
+
+    assert_eq!(hasher.finalize(), ripemd160(b"abc"));
+}
+
+// -------------------------------------------------------
+// 3. BITCOIN HELPERS
+// -------------------------------------------------------
+
+#[test]
+fn hash160_vector() {
+    assert_eq!(
+        hash160(b"hello").to_vec(),
+        hex("b6a9c8c230722b7c748331a8b450f05566dc7d0f")
+    );
+}
+
+#[test]
+fn sha256d_vector() {
+    assert_eq!(
+        sha256d(b"hello").0.to_vec(),
+        hex("9595c9df90075148eb06860365df33584b75bff782a510c6cd4883a419833d50")
+    );
+}
+
+#[test]
+fn sha256d_is_sha256_twice() {
+    assert_eq!(sha256d(b"abc"), sha256(&sha256(b"abc").0));
+}
+
+#[test]
+fn txid_hex_is_byte_reversed() {
+    assert_eq!(
+        txid_hex(&sha256d(b"hello")),
+        "503d8319a48348cdc610a582f7bf754b5833df65038606eb48510790dfc99595"
+    );
+}
+
+#[test]
+fn reverse_bytes_round_trips() {
+    let hash = sha256d(b"hello");
+    let reversed = reverse_bytes(&hash);
+
+    assert_eq!(reversed.0[0], hash.0[31]);
+    assert_eq!(reverse_bytes(&reversed), hash);
+}
@@ -0,0 +1,136 @@
+use cryptography::kdf::scrypt::{Params, ScryptError, scrypt, scrypt_parallel};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+// -------------------------------------------------------
+// 1. RFC 7914 SECTION 12 VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn scrypt_rfc7914_empty() {
+    let params = Params::new(16, 1, 1).unwrap();
+
+    assert_eq!(
+        scrypt(b"", b"", &params, 64).unwrap(),
+        hex(
+            "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"
+        )
+    );
+}
+
+#[test]
+fn scrypt_rfc7914_password_nacl() {
+    let params = Params::new(1024, 8, 16).unwrap();
+    let expected = hex(
+        "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b3731622eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640",
+    );
+
+    assert_eq!(scrypt(b"password", b"NaCl", &params, 64).unwrap(), expected);
+    assert_eq!(
+        scrypt_parallel(b"password", b"NaCl", &params, 64, 4).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn scrypt_rfc7914_pleaseletmein() {
+    let params = Params::new(16384, 8, 1).unwrap();
+
+    assert_eq!(
+        scrypt(b"pleaseletmein", b"SodiumChloride", &params, 64).unwrap(),
+        hex(
+            "7023bdcb3afd7348461c06cd81fd38ebfda8fbba904f8e3ea9b543f6545da1f2d5432955613f0fcf62d49705242a9af9e61e85dc0d651e40dfcf017b45575887"
+        )
+    );
+}
+
+#[test]
+#[ignore = "allocates 1 GiB"]
+fn scrypt_rfc7914_one_gib() {
+    let params = Params::new(1 << 20, 8, 1).unwrap();
+
+    assert_eq!(
+        scrypt(b"pleaseletmein", b"SodiumChloride", &params, 64).unwrap(),
+        hex(
+            "2101cb9b6a511aaeaddbbe09cf70f881ec568d574a2ffd4dabe5ee9820adaa478e56fd8f4ba5d09ffa1c6d927c40f4c337304049e8a952fbcbf45c6fa77a41a4"
+        )
+    );
+}
+
+// -------------------------------------------------------
+// 2. PARAMETER VALIDATION
+// -------------------------------------------------------
+
+#[test]
+fn scrypt_rejects_invalid_parameters() {
+    assert_eq!(Params::new(0, 8, 1), Err(ScryptError::InvalidN(0)));
+    assert_eq!(Params::new(1, 8, 1), Err(ScryptError::InvalidN(1)));
+    assert_eq!(Params::new(1000, 8, 1), Err(ScryptError::InvalidN(1000)));
+    assert_eq!(
+        Params::new(1 << 16, 1, 1),
+        Err(ScryptError::InvalidN(1 << 16))
+    );
+    assert_eq!(Params::new(1024, 0, 1), Err(ScryptError::InvalidR(0)));
+    assert_eq!(Params::new(1024, 8, 0), Err(ScryptError::InvalidP(0)));
+    assert_eq!(
+        Params::new(1024, 1 << 15, 1 << 15),
+        Err(ScryptError::RpTooLarge {
+            r: 1 << 15,
+            p: 1 << 15
+        })
+    );
+    assert_eq!(Params::new(1 << 62, 8, 1), Err(ScryptError::MemoryOverflow));
+
+    assert!(Params::new(1 << 15, 1, 1).is_ok());
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn scrypt_rejects_too_long_output() {
+    let params = Params::new(16, 1, 1).unwrap();
+    let too_long = u32::MAX as usize * 32 + 1;
+
+    for len in [too_long, usize::MAX] {
+        assert_eq!(
+            scrypt(b"password", b"salt", &params, len),
+            Err(ScryptError::OutputTooLong(len))
+        );
+        assert_eq!(
+            scrypt_parallel(b"password", b"salt", &params, len, 2),
+            Err(ScryptError::OutputTooLong(len))
+        );
+    }
+}
+
+#[test]
+fn scrypt_params_accessors() {
+    let params = Params::new(16384, 8, 2).unwrap();
+
+    assert_eq!((params.n(), params.r(), params.p()), (16384, 8, 2));
+    assert_eq!(params.memory_per_lane(), 16 * 1024 * 1024);
+}
+
+// -------------------------------------------------------
+// 3. PARALLEL VARIANT
+// -------------------------------------------------------
+
+#[test]
+fn scrypt_parallel_matches_serial() {
+    for p in [1, 2, 3, 5] {
+        let params = Params::new(64, 2, p).unwrap();
+        let serial = scrypt(b"password", b"salt", &params, 40).unwrap();
+
+        for threads in [0, 1, 2, 4, 8] {
+            assert_eq!(
+                scrypt_parallel(b"password", b"salt", &params, 40, threads).unwrap(),
+                serial,
+                "p {p}, threads {threads}"
+            );
+        }
+    }
+}
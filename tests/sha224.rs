@@ -0,0 +1,80 @@
+use cryptography::hash::sha224::H224;
+use cryptography::hash::{Sha224, sha224};
+
+fn h224(hex: &str) -> H224 {
+    let mut out = [0u8; 28];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).unwrap();
+    }
+    H224(out)
+}
+
+// -------------------------------------------------------
+// FIPS 180-4 EXAMPLE VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn sha224_empty_vector() {
+    assert_eq!(
+        sha224(&[]),
+        h224("d14a028c2a3a2bc9476102bb288234c415a2b01f828ea62ac5b3e42f")
+    );
+}
+
+#[test]
+fn sha224_abc_vector() {
+    assert_eq!(
+        sha224(b"abc"),
+        h224("23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7")
+    );
+}
+
+#[test]
+fn sha224_two_block_vector() {
+    assert_eq!(
+        sha224(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+        h224("75388b16512776cc5dba5da1fd890150b0c6455cb4f58b1952522525")
+    );
+}
+
+#[test]
+fn sha224_million_a() {
+    let buf = vec![b'a'; 1_000_000];
+    assert_eq!(
+        sha224(&buf),
+        h224("20794655980c91d8bbb4c1ea97618a4bf03f42581948b2ee4ee7ad67")
+    );
+}
+
+// -------------------------------------------------------
+// STREAMING
+// -------------------------------------------------------
+
+#[test]
+fn sha224_stream_matches_one_shot() {
+    let buf: Vec<u8> = (0..300u32).map(|i| (i * 7 + 3) as u8).collect();
+
+    for split in [0, 1, 27, 28, 55, 56, 64, 65, 128, 299, 300] {
+        let mut hasher = Sha224::new();
+        hasher.update(&buf[..split]);
+        hasher.update(&buf[split..]);
+        assert_eq!(hasher.finalize(), sha224(&buf), "split {split}");
+    }
+}
+
+#[test]
+fn sha224_reset_restores_iv() {
+    let mut hasher = Sha224::new();
+    hasher.update(b"discarded");
+    hasher.reset();
+    hasher.update(b"abc");
+
+    assert_eq!(hasher.finalize(), sha224(b"abc"));
+}
+
+#[test]
+fn sha224_display_format() {
+    let shown = sha224(b"abc").to_string();
+    assert!(shown.starts_with("23:09:7D:22"));
+    assert_eq!(shown.len(), 28 * 3 - 1);
+}
@@ -1,5 +1,21 @@
-use cryptography::hash::sha256;
+use cryptography::hash::output::HexError;
+use cryptography::hash::sha256::H256_INIT;
+use cryptography::hash::sha256::backend::{
+    Backend, BackendError, active_backend, set_force_portable,
+};
+use cryptography::hash::sha256::const_fn::{compress_const, sha256_const};
+use cryptography::hash::sha256::core::{compress, compress_blocks};
+use cryptography::hash::sha256::many::MultiBuffer;
+use cryptography::hash::sha256::midstate::{Midstate, MidstateError};
+use cryptography::hash::sha256::tagged::{TaggedHasher, tagged_hash};
+use cryptography::hash::sha256::tree::{
+    DEFAULT_CHUNK_LEN, MIN_CHUNK_LEN, TreeError, TreeHasher, sha256_tree,
+};
+use cryptography::hash::{Sha256, Sha256Digest, sha256, sha256_digest, sha256_many};
 use cryptography::primitives::U256;
+use cryptography::primitives::ct::ct_eq;
+
+use std::sync::Mutex;
 
 fn sha256_u256(input: &[u8]) -> U256 {
     let got = sha256(input);
@@ -198,3 +214,605 @@ fn sha256_large_boundary_10k() {
     let buf = vec![0x55u8; 10_000];
     let _ = sha256_u256(&buf);
 }
+
+// -------------------------------------------------------
+// 7. STREAMING HASHER
+// -------------------------------------------------------
+
+fn sample(len: usize) -> Vec<u8> {
+    let mut seed = 0x0F1E2D3C4B5A6978u64;
+    (0..len).map(|_| lcg(&mut seed)).collect()
+}
+
+#[test]
+fn sha256_stream_vectors() {
+    let mut hasher = Sha256::new();
+    hasher.update(b"abc");
+    assert_eq!(hasher.finalize(), EXPECT_ABC);
+
+    assert_eq!(Sha256::new().finalize(), EXPECT_EMPTY);
+}
+
+#[test]
+fn sha256_stream_every_split() {
+    let buf = sample(200);
+
+    for len in [0, 1, 55, 56, 63, 64, 65, 119, 120, 128, 200] {
+        let input = &buf[..len];
+        let expected = sha256(input);
+
+        for split in 0..=len {
+            let mut hasher = Sha256::new();
+            hasher.update(&input[..split]);
+            hasher.update(&input[split..]);
+            assert_eq!(hasher.finalize(), expected, "len {len}, split {split}");
+        }
+    }
+}
+
+#[test]
+fn sha256_stream_byte_by_byte() {
+    let buf = sample(1000);
+    let mut hasher = Sha256::new();
+
+    for b in &buf {
+        hasher.update(&[*b]);
+    }
+
+    assert_eq!(hasher.finalize(), sha256(&buf));
+}
+
+#[test]
+fn sha256_stream_uneven_chunks() {
+    let buf = sample(5000);
+
+    for step in [3, 7, 63, 64, 65, 127, 1000] {
+        let mut hasher = Sha256::new();
+        for chunk in buf.chunks(step) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.finalize(), sha256(&buf), "step {step}");
+    }
+}
+
+#[test]
+fn sha256_stream_clone_forks_state() {
+    let mut hasher = Sha256::new();
+    hasher.update(b"The quick brown fox ");
+
+    let mut fork = hasher.clone();
+    hasher.update(b"jumps over the lazy dog");
+    fork.update(b"jumps over the lazy dog.");
+
+    assert_eq!(hasher.finalize(), EXPECT_QBF);
+    assert_eq!(fork.finalize(), EXPECT_QBF_DOT);
+}
+
+#[test]
+fn sha256_stream_reset() {
+    let mut hasher = Sha256::new();
+    hasher.update(b"garbage that should be discarded");
+    hasher.reset();
+    hasher.update(b"abc");
+
+    assert_eq!(hasher.finalize(), EXPECT_ABC);
+}
+
+// -------------------------------------------------------
+// 8. DIGEST OUTPUT TYPE
+// -------------------------------------------------------
+
+const ABC_HEX: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+#[test]
+fn sha256_digest_matches_shim() {
+    assert_eq!(sha256_digest(b"abc").to_u256(), sha256(b"abc"));
+    assert_eq!(U256::from(sha256_digest(b"abc")), EXPECT_ABC);
+
+    let mut hasher = Sha256::new();
+    hasher.update(b"abc");
+    assert_eq!(hasher.finalize_digest(), sha256_digest(b"abc"));
+}
+
+#[test]
+fn sha256_digest_lowercase_hex() {
+    let digest = sha256_digest(b"abc");
+
+    assert_eq!(digest.to_string(), ABC_HEX);
+    assert_eq!(format!("{:x}", digest), ABC_HEX);
+    assert_eq!(format!("{:#x}", digest), format!("0x{ABC_HEX}"));
+    assert_eq!(format!("{:X}", digest), ABC_HEX.to_uppercase());
+}
+
+#[test]
+fn sha256_digest_hex_round_trip() {
+    let digest = sha256_digest(b"abc");
+
+    assert_eq!(Sha256Digest::from_hex(ABC_HEX), Ok(digest));
+    assert_eq!(ABC_HEX.to_uppercase().parse::<Sha256Digest>(), Ok(digest));
+}
+
+#[test]
+fn sha256_digest_hex_errors() {
+    assert_eq!(
+        Sha256Digest::from_hex("abcd"),
+        Err(HexError::InvalidLength {
+            expected: 64,
+            found: 4
+        })
+    );
+
+    let mut bad = ABC_HEX.to_string();
+    bad.replace_range(10..11, "g");
+    assert_eq!(
+        Sha256Digest::from_hex(&bad),
+        Err(HexError::InvalidCharacter(10))
+    );
+}
+
+#[test]
+fn sha256_digest_equality_and_bytes() {
+    let a = sha256_digest(b"abc");
+    let b = sha256_digest(b"abd");
+
+    assert!(a.ct_eq(&a));
+    assert_ne!(a, b);
+    assert_eq!(a.as_ref(), &EXPECT_ABC.0[..]);
+    assert_eq!(<[u8; 32]>::from(a), EXPECT_ABC.0);
+}
+
+#[test]
+fn ct_eq_compares_length_and_content() {
+    assert!(ct_eq(b"", b""));
+    assert!(ct_eq(b"abc", b"abc"));
+    assert!(!ct_eq(b"abc", b"abd"));
+    assert!(!ct_eq(b"abc", b"abcd"));
+}
+
+// -------------------------------------------------------
+// 9. MIDSTATE
+// -------------------------------------------------------
+
+#[test]
+fn midstate_resumes_hashing() {
+    let msg: Vec<u8> = (0..300u32).map(|i| (i * 13 + 1) as u8).collect();
+
+    for blocks in [0, 1, 2, 4] {
+        let prefix = &msg[..blocks * 64];
+        let midstate = Midstate::from_prefix(prefix).unwrap();
+        assert_eq!(midstate.length(), prefix.len() as u64);
+
+        let mut hasher = Sha256::from_midstate(&midstate);
+        hasher.update(&msg[blocks * 64..]);
+        assert_eq!(hasher.finalize(), sha256(&msg), "blocks {blocks}");
+    }
+}
+
+#[test]
+fn midstate_export_matches_prefix() {
+    let prefix = [0xA5u8; 128];
+
+    let mut hasher = Sha256::new();
+    hasher.update(&prefix[..10]);
+    hasher.update(&prefix[10..]);
+
+    assert_eq!(hasher.midstate(), Midstate::from_prefix(&prefix));
+}
+
+#[test]
+fn midstate_rejects_partial_blocks() {
+    let mut hasher = Sha256::new();
+    hasher.update(&[0u8; 70]);
+
+    assert_eq!(hasher.midstate(), Err(MidstateError::PartialBlock(6)));
+    assert_eq!(
+        Midstate::from_prefix(&[0u8; 65]),
+        Err(MidstateError::UnalignedLength(65))
+    );
+    assert_eq!(
+        Midstate::new([0u32; 8], 100),
+        Err(MidstateError::UnalignedLength(100))
+    );
+}
+
+#[test]
+fn midstate_serialization_is_stable() {
+    let empty = Midstate::from_prefix(&[]).unwrap().to_bytes();
+    assert_eq!(&empty[..4], &[0x6a, 0x09, 0xe6, 0x67]);
+    assert_eq!(&empty[32..], &[0u8; 8]);
+
+    let midstate = Midstate::from_prefix(&[7u8; 192]).unwrap();
+    let bytes = midstate.to_bytes();
+    assert_eq!(&bytes[32..], &192u64.to_be_bytes());
+    assert_eq!(Midstate::from_bytes(&bytes), Ok(midstate));
+
+    let mut bad = bytes;
+    bad[39] |= 1;
+    assert_eq!(
+        Midstate::from_bytes(&bad),
+        Err(MidstateError::UnalignedLength(193))
+    );
+}
+
+// -------------------------------------------------------
+// 10. COMPRESSION BACKENDS
+// -------------------------------------------------------
+
+fn pseudo_random_blocks(count: usize, seed: u32) -> Vec<[u8; 64]> {
+    let mut x = seed;
+    (0..count)
+        .map(|_| {
+            let mut block = [0u8; 64];
+            for byte in block.iter_mut() {
+                x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                *byte = (x >> 16) as u8;
+            }
+            block
+        })
+        .collect()
+}
+
+#[test]
+fn backends_agree_with_portable() {
+    for backend in [Backend::Portable, Backend::ShaNi] {
+        if !backend.is_available() {
+            continue;
+        }
+
+        for count in [0, 1, 2, 7, 33] {
+            let blocks = pseudo_random_blocks(count, count as u32 + 1);
+
+            let mut expected = H256_INIT;
+            for block in &blocks {
+                compress(block, &mut expected);
+            }
+
+            let mut state = H256_INIT;
+            backend.compress_blocks(&mut state, &blocks).unwrap();
+            assert_eq!(state, expected, "{backend:?}, {count} blocks");
+        }
+    }
+}
+
+#[test]
+fn unavailable_backend_is_an_error() {
+    let blocks = pseudo_random_blocks(2, 9);
+
+    for backend in [Backend::Portable, Backend::ShaNi] {
+        let mut state = H256_INIT;
+        let result = backend.compress_blocks(&mut state, &blocks);
+
+        if backend.is_available() {
+            assert_eq!(result, Ok(()));
+        } else {
+            assert_eq!(result, Err(BackendError::Unavailable(backend)));
+            assert_eq!(state, H256_INIT);
+        }
+    }
+}
+
+#[test]
+fn detected_backend_is_available() {
+    assert!(Backend::detect().is_available());
+    assert!(active_backend().is_available());
+}
+
+/// Serializes the tests that toggle the process-wide backend override.
+static BACKEND_OVERRIDE: Mutex<()> = Mutex::new(());
+
+#[test]
+fn forced_portable_matches_dispatch() {
+    let _guard = BACKEND_OVERRIDE.lock().unwrap();
+
+    let buf: Vec<u8> = (0..10_000u32).map(|i| (i * 7 + 11) as u8).collect();
+    let dispatched = sha256(&buf);
+
+    set_force_portable(true);
+    assert_eq!(active_backend(), Backend::Portable);
+    let portable = sha256(&buf);
+    set_force_portable(false);
+
+    assert_eq!(portable, dispatched);
+}
+
+#[test]
+fn short_messages_match_across_backends() {
+    let _guard = BACKEND_OVERRIDE.lock().unwrap();
+
+    let buf: Vec<u8> = (0..130u32).map(|i| (i * 13 + 5) as u8).collect();
+
+    // One-shot, byte-wise streaming, tagged and midstate hashing of every length 0..=130
+    let run = || -> Vec<(U256, U256, U256, U256)> {
+        (0..=buf.len())
+            .map(|len| {
+                let msg = &buf[..len];
+
+                let mut streamed = Sha256::new();
+                for byte in msg {
+                    streamed.update(&[*byte]);
+                }
+
+                let prefix = Midstate::from_prefix(&buf[..64]).unwrap();
+                let mut resumed = Sha256::from_midstate(&prefix);
+                resumed.update(msg);
+
+                (
+                    sha256(msg),
+                    streamed.finalize(),
+                    tagged_hash("backend/test", msg),
+                    resumed.finalize(),
+                )
+            })
+            .collect()
+    };
+
+    set_force_portable(true);
+    let portable = run();
+    set_force_portable(false);
+    let dispatched = run();
+
+    for (len, (p, d)) in portable.iter().zip(&dispatched).enumerate() {
+        assert_eq!(p, d, "length {len}");
+    }
+}
+
+// -------------------------------------------------------
+// 11. MULTI-BUFFER HASHING
+// -------------------------------------------------------
+
+fn mixed_messages() -> Vec<Vec<u8>> {
+    // Lengths straddle the padding boundaries and vary widely within each batch
+    [
+        0, 1, 55, 56, 63, 64, 65, 119, 120, 128, 200, 1000, 3, 4096, 17, 64, 55, 300, 9,
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, &len)| (0..len).map(|j| (i * 31 + j * 7) as u8).collect())
+    .collect()
+}
+
+#[test]
+fn sha256_many_matches_sha256() {
+    let messages = mixed_messages();
+    let inputs: Vec<&[u8]> = messages.iter().map(Vec::as_slice).collect();
+
+    let hashes = sha256_many(&inputs);
+    assert_eq!(hashes.len(), inputs.len());
+
+    for (input, hash) in inputs.iter().zip(hashes) {
+        assert_eq!(hash, sha256(input), "len {}", input.len());
+    }
+}
+
+#[test]
+fn sha256_many_every_implementation() {
+    let messages = mixed_messages();
+    let inputs: Vec<&[u8]> = messages.iter().map(Vec::as_slice).collect();
+    let expected: Vec<U256> = inputs.iter().map(|input| sha256(input)).collect();
+
+    for imp in [MultiBuffer::Scalar, MultiBuffer::Sse2, MultiBuffer::Avx2] {
+        if !imp.is_available() {
+            continue;
+        }
+
+        // Every batch size, including partially filled lane groups
+        for count in 0..=inputs.len() {
+            assert_eq!(
+                imp.sha256_many(&inputs[..count]),
+                expected[..count],
+                "{imp:?}, {count} inputs"
+            );
+        }
+    }
+}
+
+// -------------------------------------------------------
+// 12. MULTI-BLOCK COMPRESSION
+// -------------------------------------------------------
+
+#[test]
+fn compress_blocks_matches_single_block_calls() {
+    for count in [0, 1, 3, 16] {
+        let blocks = pseudo_random_blocks(count, 99 + count as u32);
+
+        let mut expected = H256_INIT;
+        for block in &blocks {
+            compress(block, &mut expected);
+        }
+
+        let mut state = H256_INIT;
+        compress_blocks(&mut state, &blocks);
+        assert_eq!(state, expected, "{count} blocks");
+    }
+}
+
+#[test]
+fn compress_blocks_hashes_padded_message() {
+    // "abc" padded to a single block by hand
+    let mut block = [0u8; 64];
+    block[..3].copy_from_slice(b"abc");
+    block[3] = 0x80;
+    block[63] = 24;
+
+    let mut state = H256_INIT;
+    compress_blocks(&mut state, &[block]);
+
+    assert_eq!(U256::from(state), EXPECT_ABC);
+}
+
+// -------------------------------------------------------
+// 13. CONST FN
+// -------------------------------------------------------
+
+const CONST_EMPTY: U256 = sha256_const(b"");
+const CONST_ABC: U256 = sha256_const(b"abc");
+const CONST_LONG_MSG: U256 =
+    sha256_const(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq");
+
+#[test]
+fn sha256_const_official_vectors() {
+    assert_eq!(CONST_EMPTY, EXPECT_EMPTY);
+    assert_eq!(CONST_ABC, EXPECT_ABC);
+    assert_eq!(CONST_LONG_MSG, EXPECT_LONG_MSG);
+    assert_eq!(
+        sha256_const(b"The quick brown fox jumps over the lazy dog"),
+        EXPECT_QBF
+    );
+    assert_eq!(
+        sha256_const(b"The quick brown fox jumps over the lazy dog."),
+        EXPECT_QBF_DOT
+    );
+}
+
+#[test]
+fn sha256_const_matches_runtime_across_lengths() {
+    for len in 0..=300 {
+        let input = sample(len);
+        assert_eq!(sha256_const(&input), sha256(&input), "len {len}");
+    }
+}
+
+#[test]
+fn compress_const_matches_compress() {
+    for block in pseudo_random_blocks(8, 7) {
+        let mut expected = H256_INIT;
+        compress(&block, &mut expected);
+
+        assert_eq!(compress_const(&block, H256_INIT), expected);
+    }
+}
+
+// -------------------------------------------------------
+// 14. TAGGED HASHES
+// -------------------------------------------------------
+
+fn tagged_reference(tag: &str, msg: &[u8]) -> U256 {
+    let tag_hash = sha256(tag.as_bytes());
+    sha256(&[&tag_hash.0[..], &tag_hash.0[..], msg].concat())
+}
+
+#[test]
+fn tagged_hash_matches_definition() {
+    for tag in ["", "BIP0340/challenge", "nebula/node-id"] {
+        for len in [0, 1, 55, 64, 200] {
+            let msg = sample(len);
+            assert_eq!(
+                tagged_hash(tag, &msg),
+                tagged_reference(tag, &msg),
+                "tag {tag:?}, len {len}"
+            );
+        }
+    }
+}
+
+#[test]
+fn tagged_hasher_reuses_midstate() {
+    let hasher = TaggedHasher::new("BIP0340/aux");
+    assert_eq!(hasher.midstate().length(), 64);
+
+    for len in [0, 10, 100] {
+        let msg = sample(len);
+        assert_eq!(hasher.hash(&msg), tagged_reference("BIP0340/aux", &msg));
+    }
+
+    let mut stream = hasher.start();
+    stream.update(b"split ");
+    stream.update(b"message");
+    assert_eq!(stream.finalize(), hasher.hash(b"split message"));
+}
+
+#[test]
+fn tagged_hashes_are_domain_separated() {
+    assert_ne!(tagged_hash("a", b"msg"), tagged_hash("b", b"msg"));
+    assert_ne!(tagged_hash("a", b"msg"), sha256(b"msg"));
+}
+
+// -------------------------------------------------------
+// 15. TREE MODE
+// -------------------------------------------------------
+
+/// Serial reference of the version 1 tree mode, written from the specification.
+fn tree_reference(input: &[u8], chunk_len: usize) -> U256 {
+    fn node(chunks: &[&[u8]]) -> U256 {
+        if chunks.len() == 1 {
+            return sha256(&[&[0x00][..], chunks[0]].concat());
+        }
+
+        // Level-by-level pairing with odd promotion: the left subtree is the largest power of two
+        let split = chunks.len().next_power_of_two() / 2;
+        let left = node(&chunks[..split]);
+        let right = node(&chunks[split..]);
+
+        sha256(&[&[0x01][..], &left.0, &right.0].concat())
+    }
+
+    let chunks: Vec<&[u8]> = if input.is_empty() {
+        vec![input]
+    } else {
+        input.chunks(chunk_len).collect()
+    };
+
+    let root = node(&chunks);
+    sha256(
+        &[
+            &[0x02, 1][..],
+            &(chunk_len as u64).to_be_bytes(),
+            &(input.len() as u64).to_be_bytes(),
+            &root.0,
+        ]
+        .concat(),
+    )
+}
+
+#[test]
+fn tree_matches_serial_reference() {
+    for len in [0, 1, 1023, 1024, 1025, 3 * 1024, 5 * 1024 + 7, 13 * 1024] {
+        let input = sample(len);
+        let expected = tree_reference(&input, 1024);
+
+        for threads in [1, 2, 3, 8] {
+            let got = TreeHasher::new()
+                .chunk_len(1024)
+                .unwrap()
+                .threads(threads)
+                .hash(&input);
+            assert_eq!(got, expected, "len {len}, {threads} threads");
+        }
+    }
+}
+
+#[test]
+fn tree_default_parameters() {
+    let input = sample(2 * DEFAULT_CHUNK_LEN + 100);
+
+    assert_eq!(
+        sha256_tree(&input),
+        tree_reference(&input, DEFAULT_CHUNK_LEN)
+    );
+    assert_eq!(
+        sha256_tree(&input),
+        TreeHasher::new().threads(2).hash(&input)
+    );
+}
+
+#[test]
+fn tree_binds_chunk_len_and_differs_from_sha256() {
+    let input = sample(100);
+
+    assert_ne!(
+        TreeHasher::new().chunk_len(1024).unwrap().hash(&input),
+        TreeHasher::new().chunk_len(2048).unwrap().hash(&input)
+    );
+    assert_ne!(sha256_tree(&input), sha256(&input));
+}
+
+#[test]
+fn tree_rejects_invalid_chunk_len() {
+    for len in [0, 512, MIN_CHUNK_LEN - 1, 3 * 1024, usize::MAX] {
+        assert_eq!(
+            TreeHasher::new().chunk_len(len),
+            Err(TreeError::InvalidChunkLen(len))
+        );
+    }
+    assert!(TreeHasher::new().chunk_len(MIN_CHUNK_LEN).is_ok());
+}
@@ -0,0 +1,202 @@
+use cryptography::hash::sha3::keccak::keccak_f1600;
+use cryptography::hash::sha3::sponge::{DOMAIN_SHA3, Sponge};
+use cryptography::hash::{
+    Sha3_224, Sha3_256, Sha3_384, Sha3_512, sha3_224, sha3_256, sha3_384, sha3_512,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+const ABC: &[u8] = b"abc";
+const LONG_MSG: &[u8] = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+
+// -------------------------------------------------------
+// 1. KECCAK-F[1600] PERMUTATION
+// -------------------------------------------------------
+
+#[test]
+fn keccak_f1600_zero_state() {
+    // First lanes of Keccak-f[1600] applied to the all-zero state (Keccak team reference)
+    let mut state = [0u64; 25];
+    keccak_f1600(&mut state);
+
+    assert_eq!(state[0], 0xF1258F7940E1DDE7);
+    assert_eq!(state[1], 0x84D5CCF933C0478A);
+    assert_eq!(state[24], 0xEAF1FF7B5CECA249);
+}
+
+// -------------------------------------------------------
+// 2. FIPS 202 VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn sha3_224_vectors() {
+    assert_eq!(
+        sha3_224(&[]).0.to_vec(),
+        hex("6b4e03423667dbb73b6e15454f0eb1abd4597f9a1b078e3f5b5a6bc7")
+    );
+    assert_eq!(
+        sha3_224(ABC).0.to_vec(),
+        hex("e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf")
+    );
+    assert_eq!(
+        sha3_224(LONG_MSG).0.to_vec(),
+        hex("8a24108b154ada21c9fd5574494479ba5c7e7ab76ef264ead0fcce33")
+    );
+}
+
+#[test]
+fn sha3_256_vectors() {
+    assert_eq!(
+        sha3_256(&[]).0.to_vec(),
+        hex("a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a")
+    );
+    assert_eq!(
+        sha3_256(ABC).0.to_vec(),
+        hex("3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532")
+    );
+    assert_eq!(
+        sha3_256(LONG_MSG).0.to_vec(),
+        hex("41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376")
+    );
+}
+
+#[test]
+fn sha3_384_vectors() {
+    assert_eq!(
+        sha3_384(&[]).0.to_vec(),
+        hex(
+            "0c63a75b845e4f7d01107d852e4c2485c51a50aaaa94fc61995e71bbee983a2ac3713831264adb47fb6bd1e058d5f004"
+        )
+    );
+    assert_eq!(
+        sha3_384(ABC).0.to_vec(),
+        hex(
+            "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25"
+        )
+    );
+}
+
+#[test]
+fn sha3_512_vectors() {
+    assert_eq!(
+        sha3_512(&[]).0.to_vec(),
+        hex(
+            "a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26"
+        )
+    );
+    assert_eq!(
+        sha3_512(ABC).0.to_vec(),
+        hex(
+            "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"
+        )
+    );
+}
+
+#[test]
+fn sha3_multi_block_a3_vectors() {
+    // 200 bytes of 0xA3: the 1600-bit message from the NIST example files
+    let buf = [0xA3u8; 200];
+
+    assert_eq!(
+        sha3_224(&buf).0.to_vec(),
+        hex("9376816aba503f72f96ce7eb65ac095deee3be4bf9bbc2a1cb7e11e0")
+    );
+    assert_eq!(
+        sha3_256(&buf).0.to_vec(),
+        hex("79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787")
+    );
+    assert_eq!(
+        sha3_384(&buf).0.to_vec(),
+        hex(
+            "1881de2ca7e41ef95dc4732b8f5f002b189cc1e42b74168ed1732649ce1dbcdd76197a31fd55ee989f2d7050dd473e8f"
+        )
+    );
+    assert_eq!(
+        sha3_512(&buf).0.to_vec(),
+        hex(
+            "e76dfad22084a8b1467fcf2ffa58361bec7628edf5f3fdc0e4805dc48caeeca81b7c13c30adf52a3659584739a2df46be589c51ca1a4a8416df6545a1ce8ba00"
+        )
+    );
+}
+
+// -------------------------------------------------------
+// 3. STREAMING AND SPONGE
+// -------------------------------------------------------
+
+fn sample(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 13 + 5) as u8).collect()
+}
+
+#[test]
+fn sha3_256_stream_every_split() {
+    let buf = sample(300);
+
+    for len in [0, 1, 135, 136, 137, 271, 272, 300] {
+        let input = &buf[..len];
+        let expected = sha3_256(input);
+
+        for split in 0..=len {
+            let mut hasher = Sha3_256::new();
+            hasher.update(&input[..split]);
+            hasher.update(&input[split..]);
+            assert_eq!(hasher.finalize(), expected, "len {len}, split {split}");
+        }
+    }
+}
+
+#[test]
+fn sha3_variants_stream_match_one_shot() {
+    let buf = sample(1000);
+
+    for step in [1, 7, 72, 104, 144, 333] {
+        let mut s224 = Sha3_224::new();
+        let mut s384 = Sha3_384::new();
+        let mut s512 = Sha3_512::new();
+
+        for chunk in buf.chunks(step) {
+            s224.update(chunk);
+            s384.update(chunk);
+            s512.update(chunk);
+        }
+
+        assert_eq!(s224.finalize(), sha3_224(&buf), "step {step}");
+        assert_eq!(s384.finalize(), sha3_384(&buf), "step {step}");
+        assert_eq!(s512.finalize(), sha3_512(&buf), "step {step}");
+    }
+}
+
+#[test]
+fn sha3_stream_clone_and_reset() {
+    let mut hasher = Sha3_256::new();
+    hasher.update(b"a");
+
+    let mut fork = hasher.clone();
+    fork.update(b"bc");
+    assert_eq!(fork.finalize(), sha3_256(ABC));
+
+    hasher.reset();
+    assert_eq!(hasher.finalize(), sha3_256(&[]));
+}
+
+#[test]
+fn sponge_matches_sha3_256() {
+    let mut sponge = Sponge::new(136, DOMAIN_SHA3);
+    assert_eq!(sponge.capacity(), 64);
+
+    sponge.absorb(LONG_MSG);
+
+    let mut out = [0u8; 32];
+    sponge.finalize().squeeze(&mut out);
+    assert_eq!(out, sha3_256(LONG_MSG).0);
+}
+
+#[test]
+#[should_panic]
+fn sponge_rejects_invalid_rate() {
+    let _ = Sponge::new(201, DOMAIN_SHA3);
+}
@@ -0,0 +1,176 @@
+use cryptography::hash::{
+    Sha384, Sha512, Sha512_224, Sha512_256, sha384, sha512, sha512_224, sha512_256,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+const ABC: &[u8] = b"abc";
+const TWO_BLOCK: &[u8] = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+
+// -------------------------------------------------------
+// 1. FIPS 180-4 EXAMPLE VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn sha512_vectors() {
+    assert_eq!(
+        sha512(&[]).0.to_vec(),
+        hex(
+            "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e"
+        )
+    );
+    assert_eq!(
+        sha512(ABC).0.to_vec(),
+        hex(
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        )
+    );
+    assert_eq!(
+        sha512(TWO_BLOCK).0.to_vec(),
+        hex(
+            "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909"
+        )
+    );
+}
+
+#[test]
+fn sha384_vectors() {
+    assert_eq!(
+        sha384(&[]).0.to_vec(),
+        hex(
+            "38b060a751ac96384cd9327eb1b1e36a21fdb71114be07434c0cc7bf63f6e1da274edebfe76f65fbd51ad2f14898b95b"
+        )
+    );
+    assert_eq!(
+        sha384(ABC).0.to_vec(),
+        hex(
+            "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"
+        )
+    );
+    assert_eq!(
+        sha384(TWO_BLOCK).0.to_vec(),
+        hex(
+            "09330c33f71147e83d192fc782cd1b4753111b173b3b05d22fa08086e3b0f712fcc7c71a557e2db966c3e9fa91746039"
+        )
+    );
+}
+
+#[test]
+fn sha512_224_vectors() {
+    assert_eq!(
+        sha512_224(&[]).0.to_vec(),
+        hex("6ed0dd02806fa89e25de060c19d3ac86cabb87d6a0ddd05c333b84f4")
+    );
+    assert_eq!(
+        sha512_224(ABC).0.to_vec(),
+        hex("4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa")
+    );
+    assert_eq!(
+        sha512_224(TWO_BLOCK).0.to_vec(),
+        hex("23fec5bb94d60b23308192640b0c453335d664734fe40e7268674af9")
+    );
+}
+
+#[test]
+fn sha512_256_vectors() {
+    assert_eq!(
+        sha512_256(&[]).0.to_vec(),
+        hex("c672b8d1ef56ed28ab87c3622c5114069bdd3ad7b8f9737498d0c01ecef0967a")
+    );
+    assert_eq!(
+        sha512_256(ABC).0.to_vec(),
+        hex("53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23")
+    );
+    assert_eq!(
+        sha512_256(TWO_BLOCK).0.to_vec(),
+        hex("3928e184fb8690f840da3988121d31be65cb9d3ef83ee6146feac861e19b563a")
+    );
+}
+
+#[test]
+fn sha512_family_million_a() {
+    let buf = vec![b'a'; 1_000_000];
+
+    assert_eq!(
+        sha512(&buf).0.to_vec(),
+        hex(
+            "e718483d0ce769644e2e42c7bc15b4638e1f98b13b2044285632a803afa973ebde0ff244877ea60a4cb0432ce577c31beb009c5c2c49aa2e4eadb217ad8cc09b"
+        )
+    );
+    assert_eq!(
+        sha384(&buf).0.to_vec(),
+        hex(
+            "9d0e1809716474cb086e834e310a4a1ced149e9c00f248527972cec5704c2a5b07b8b3dc38ecc4ebae97ddd87f3d8985"
+        )
+    );
+}
+
+// -------------------------------------------------------
+// 2. STREAMING
+// -------------------------------------------------------
+
+fn sample(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 31 + 7) as u8).collect()
+}
+
+#[test]
+fn sha512_stream_every_split() {
+    let buf = sample(300);
+
+    for len in [0, 1, 111, 112, 127, 128, 129, 239, 240, 256, 300] {
+        let input = &buf[..len];
+        let expected = sha512(input);
+
+        for split in 0..=len {
+            let mut hasher = Sha512::new();
+            hasher.update(&input[..split]);
+            hasher.update(&input[split..]);
+            assert_eq!(hasher.finalize(), expected, "len {len}, split {split}");
+        }
+    }
+}
+
+#[test]
+fn sha512_variants_stream_match_one_shot() {
+    let buf = sample(1000);
+
+    for step in [1, 13, 128, 129, 500] {
+        let mut s384 = Sha384::new();
+        let mut s224 = Sha512_224::new();
+        let mut s256 = Sha512_256::new();
+
+        for chunk in buf.chunks(step) {
+            s384.update(chunk);
+            s224.update(chunk);
+            s256.update(chunk);
+        }
+
+        assert_eq!(s384.finalize(), sha384(&buf), "step {step}");
+        assert_eq!(s224.finalize(), sha512_224(&buf), "step {step}");
+        assert_eq!(s256.finalize(), sha512_256(&buf), "step {step}");
+    }
+}
+
+#[test]
+fn sha512_stream_clone_and_reset() {
+    let mut hasher = Sha512::new();
+    hasher.update(b"ab");
+
+    let mut fork = hasher.clone();
+    fork.update(b"c");
+    assert_eq!(fork.finalize(), sha512(ABC));
+
+    hasher.reset();
+    hasher.update(ABC);
+    assert_eq!(hasher.finalize(), sha512(ABC));
+
+    let mut short = Sha384::new();
+    short.update(b"discarded");
+    short.reset();
+    assert_eq!(short.finalize(), sha384(&[]));
+}
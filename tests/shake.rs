@@ -0,0 +1,127 @@
+use cryptography::hash::{Shake128, Shake256, shake128, shake256};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+// -------------------------------------------------------
+// 1. NIST VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn shake128_empty_vector() {
+    assert_eq!(
+        shake128(&[], 32),
+        hex("7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26")
+    );
+}
+
+#[test]
+fn shake256_empty_vector() {
+    assert_eq!(
+        shake256(&[], 64),
+        hex(
+            "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762fd75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be"
+        )
+    );
+}
+
+#[test]
+fn shake_abc_vectors() {
+    assert_eq!(
+        shake128(b"abc", 32),
+        hex("5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8")
+    );
+    assert_eq!(
+        shake256(b"abc", 64),
+        hex(
+            "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4"
+        )
+    );
+}
+
+#[test]
+fn shake_a3_4096_bit_outputs() {
+    // 1600-bit message of 0xA3 with 4096-bit output, checked on the last 32 bytes
+    let msg = [0xA3u8; 200];
+
+    let out128 = shake128(&msg, 512);
+    assert_eq!(
+        out128[480..].to_vec(),
+        hex("44c9fb359fd56ac0a9a75a743cff6862f17d7259ab075216c0699511643b6439")
+    );
+
+    let out256 = shake256(&msg, 512);
+    assert_eq!(
+        out256[480..].to_vec(),
+        hex("6a1a9d7846436e4dca5728b6f760eef0ca92bf0be5615e96959d767197a0beeb")
+    );
+}
+
+// -------------------------------------------------------
+// 2. INCREMENTAL SQUEEZE
+// -------------------------------------------------------
+
+#[test]
+fn shake128_small_squeezes_match_large() {
+    let expected = shake128(b"incremental", 1000);
+
+    for step in [1, 7, 167, 168, 169, 500] {
+        let mut xof = Shake128::new();
+        xof.absorb(b"incre");
+        xof.absorb(b"mental");
+        let mut reader = xof.finalize();
+
+        let mut got = Vec::new();
+        while got.len() < expected.len() {
+            let n = step.min(expected.len() - got.len());
+            let mut buf = vec![0u8; n];
+            reader.squeeze(&mut buf);
+            got.extend_from_slice(&buf);
+        }
+
+        assert_eq!(got, expected, "step {step}");
+    }
+}
+
+#[test]
+fn shake256_small_squeezes_match_large() {
+    let expected = shake256(&[0x5Au8; 300], 700);
+
+    let mut xof = Shake256::new();
+    for chunk in [0x5Au8; 300].chunks(41) {
+        xof.absorb(chunk);
+    }
+    let mut reader = xof.finalize();
+
+    let mut got = Vec::new();
+    for size in [0, 1, 135, 1, 136, 137, 290] {
+        let mut buf = vec![0u8; size];
+        reader.squeeze(&mut buf);
+        got.extend_from_slice(&buf);
+    }
+
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn shake_reader_clone_continues_independently() {
+    let mut xof = Shake128::new();
+    xof.absorb(b"fork");
+    let mut reader = xof.finalize();
+
+    let mut head = [0u8; 100];
+    reader.squeeze(&mut head);
+
+    let mut fork = reader.clone();
+    let mut a = [0u8; 100];
+    let mut b = [0u8; 100];
+    reader.squeeze(&mut a);
+    fork.squeeze(&mut b);
+
+    assert_eq!(a, b);
+    assert_eq!(&shake128(b"fork", 200)[100..], &a);
+}
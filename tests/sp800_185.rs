@@ -0,0 +1,238 @@
+use cryptography::hash::shake128;
+use cryptography::hash::sp800_185::cshake::{CShake128, cshake128, cshake256};
+use cryptography::hash::sp800_185::encoding::{bytepad, encode_string, left_encode, right_encode};
+use cryptography::hash::sp800_185::kmac::{Kmac128, Kmac256, kmac128, kmac256};
+use cryptography::hash::sp800_185::parallel_hash::{
+    ParallelHash128, parallel_hash128, parallel_hash256,
+};
+use cryptography::hash::sp800_185::tuple_hash::{TupleHash128, tuple_hash128, tuple_hash256};
+
+fn hex(s: &str) -> Vec<u8> {
+    let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn range(start: u8, len: usize) -> Vec<u8> {
+    (0..len).map(|i| start.wrapping_add(i as u8)).collect()
+}
+
+// -------------------------------------------------------
+// 1. ENCODINGS
+// -------------------------------------------------------
+
+#[test]
+fn encodings_match_spec() {
+    assert_eq!(left_encode(0), vec![0x01, 0x00]);
+    assert_eq!(left_encode(255), vec![0x01, 0xFF]);
+    assert_eq!(left_encode(256), vec![0x02, 0x01, 0x00]);
+    assert_eq!(right_encode(0), vec![0x00, 0x01]);
+    assert_eq!(right_encode(65536), vec![0x01, 0x00, 0x00, 0x03]);
+    assert_eq!(
+        right_encode(u64::MAX),
+        vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x08]
+    );
+
+    assert_eq!(encode_string(b""), vec![0x01, 0x00]);
+    assert_eq!(encode_string(b"ab"), vec![0x01, 0x10, b'a', b'b']);
+
+    let padded = bytepad(b"xyz", 8);
+    assert_eq!(padded, vec![0x01, 0x08, b'x', b'y', b'z', 0, 0, 0]);
+    assert_eq!(bytepad(&[0u8; 6], 8).len(), 8);
+    assert_eq!(bytepad(&[0u8; 7], 8).len(), 16);
+}
+
+// -------------------------------------------------------
+// 2. CSHAKE (SP 800-185 SAMPLES)
+// -------------------------------------------------------
+
+#[test]
+fn cshake128_samples() {
+    assert_eq!(
+        cshake128(&range(0, 4), b"", b"Email Signature", 32),
+        hex("C1C36925B6409A04F1B504FCBCA9D82B4017277CB5ED2B2065FC1D3814D5AAF5")
+    );
+    assert_eq!(
+        cshake128(&range(0, 200), b"", b"Email Signature", 32),
+        hex("C5221D50E4F822D96A2E8881A961420F294B7B24FE3D2094BAED2C6524CC166B")
+    );
+}
+
+#[test]
+fn cshake256_samples() {
+    assert_eq!(
+        cshake256(&range(0, 4), b"", b"Email Signature", 64),
+        hex(
+            "D008828E2B80AC9D2218FFEE1D070C48B8E4C87BFF32C9699D5B6896EEE0EDD1
+             64020E2BE0560858D9C00C037E34A96937C561A74C412BB4C746469527281C8C"
+        )
+    );
+}
+
+#[test]
+fn cshake_without_strings_is_shake() {
+    assert_eq!(cshake128(b"abc", b"", b"", 64), shake128(b"abc", 64));
+}
+
+#[test]
+fn cshake_stream_matches_one_shot() {
+    let data = range(0, 200);
+
+    let mut xof = CShake128::new(b"", b"Email Signature");
+    for chunk in data.chunks(17) {
+        xof.absorb(chunk);
+    }
+
+    let mut out = [0u8; 32];
+    xof.finalize().squeeze(&mut out);
+    assert_eq!(out.to_vec(), cshake128(&data, b"", b"Email Signature", 32));
+}
+
+// -------------------------------------------------------
+// 3. KMAC (SP 800-185 SAMPLES)
+// -------------------------------------------------------
+
+#[test]
+fn kmac128_samples() {
+    let key = range(0x40, 32);
+
+    assert_eq!(
+        kmac128(&key, &range(0, 4), b"", 32),
+        hex("E5780B0D3EA6F7D3A429C5706AA43A00FADBD7D49628839E3187243F456EE14E")
+    );
+    assert_eq!(
+        kmac128(&key, &range(0, 4), b"My Tagged Application", 32),
+        hex("3B1FBA963CD8B0B59E8C1A6D71888B7143651AF8BA0A7070C0979E2811324AA5")
+    );
+    assert_eq!(
+        kmac128(&key, &range(0, 200), b"My Tagged Application", 32),
+        hex("1F5B4E6CCA02209E0DCB5CA635B89A15E271ECC760071DFD805FAA38F9729230")
+    );
+}
+
+#[test]
+fn kmac256_samples() {
+    let key = range(0x40, 32);
+
+    assert_eq!(
+        kmac256(&key, &range(0, 4), b"My Tagged Application", 64),
+        hex(
+            "20C570C31346F703C9AC36C61C03CB64C3970D0CFC787E9B79599D273A68D2F7
+             F69D4CC3DE9D104A351689F27CF6F5951F0103F33F4F24871024D9C27773A8DD"
+        )
+    );
+}
+
+#[test]
+fn kmac_length_is_bound_into_tag() {
+    let short = kmac128(b"key", b"msg", b"", 16);
+    let long = kmac128(b"key", b"msg", b"", 32);
+    assert_ne!(&long[..16], &short[..]);
+}
+
+#[test]
+fn kmac_xof_is_prefix_stable() {
+    let mut mac = Kmac256::new(b"key", b"S");
+    mac.update(b"msg");
+    let mut reader = mac.clone().finalize_xof();
+
+    let mut a = [0u8; 16];
+    reader.squeeze(&mut a);
+
+    let mut b = [0u8; 48];
+    mac.finalize_xof().squeeze(&mut b);
+    assert_eq!(&b[..16], &a);
+
+    let mut streamed = Kmac128::new(b"key", b"");
+    streamed.update(b"m");
+    streamed.update(b"sg");
+    let mut tag = [0u8; 32];
+    streamed.finalize(&mut tag);
+    assert_eq!(tag.to_vec(), kmac128(b"key", b"msg", b"", 32));
+}
+
+// -------------------------------------------------------
+// 4. TUPLEHASH (SP 800-185 SAMPLES)
+// -------------------------------------------------------
+
+#[test]
+fn tuple_hash128_samples() {
+    let a = range(0x00, 3);
+    let b = range(0x10, 6);
+
+    assert_eq!(
+        tuple_hash128(&[&a, &b], b"", 32),
+        hex("C5D8786C1AFB9B82111AB34B65B2C0048FA64E6D48E263264CE1707D3FFC8ED1")
+    );
+    assert_eq!(
+        tuple_hash128(&[&a, &b], b"My Tuple App", 32),
+        hex("75CDB20FF4DB1154E841D758E24160C54BAE86EB8C13E7F5F40EB35588E96DFB")
+    );
+}
+
+#[test]
+fn tuple_hash_is_unambiguous() {
+    assert_ne!(
+        tuple_hash256(&[b"ab", b"c"], b"", 64),
+        tuple_hash256(&[b"a", b"bc"], b"", 64)
+    );
+
+    let mut hasher = TupleHash128::new(b"My Tuple App");
+    hasher.push(&range(0x00, 3));
+    hasher.push(&range(0x10, 6));
+    let mut out = [0u8; 32];
+    hasher.finalize(&mut out);
+    assert_eq!(
+        out.to_vec(),
+        tuple_hash128(&[&range(0x00, 3), &range(0x10, 6)], b"My Tuple App", 32)
+    );
+}
+
+// -------------------------------------------------------
+// 5. PARALLELHASH (SP 800-185 SAMPLES)
+// -------------------------------------------------------
+
+fn parallel_sample() -> Vec<u8> {
+    let mut x = range(0x00, 8);
+    x.extend(range(0x10, 8));
+    x.extend(range(0x20, 8));
+    x
+}
+
+#[test]
+fn parallel_hash128_samples() {
+    let x = parallel_sample();
+
+    assert_eq!(
+        parallel_hash128(&x, 8, b"", 32),
+        hex("BA8DC1D1D979331D3F813603C67F72609AB5E44B94A0B8F9AF46514454A2B4F5")
+    );
+    assert_eq!(
+        parallel_hash128(&x, 8, b"Parallel Data", 32),
+        hex("FC484DCB3F84DCEEDC353438151BEE58157D6EFED0445A81F165E495795B7206")
+    );
+}
+
+#[test]
+fn parallel_hash_stream_matches_one_shot() {
+    let data: Vec<u8> = (0..10_000u32).map(|i| (i * 7) as u8).collect();
+    let expected = parallel_hash128(&data, 64, b"S", 32);
+
+    for step in [1, 63, 64, 65, 1000, 10_000] {
+        let mut hasher = ParallelHash128::new(64, b"S");
+        for chunk in data.chunks(step) {
+            hasher.update(chunk);
+        }
+
+        let mut out = [0u8; 32];
+        hasher.finalize(&mut out);
+        assert_eq!(out.to_vec(), expected, "step {step}");
+    }
+
+    assert_ne!(
+        parallel_hash256(&data, 64, b"S", 32),
+        parallel_hash256(&data, 128, b"S", 32)
+    );
+}